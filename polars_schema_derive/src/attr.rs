@@ -0,0 +1,461 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, Ident, LitInt, LitStr, Path, Type};
+
+use crate::case::RenameRule;
+use crate::constraint;
+use crate::dtype::parse_dtype;
+use crate::kind::{ColumnKind, Requirement, NUMERIC, STRING, STRING_OR_LIST};
+use crate::timeseries::{self, TimeSeriesAttrs};
+use crate::{sorted, statistic};
+
+/// Struct-level `#[polars(...)]` options.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub primary_key: Option<Vec<Ident>>,
+    /// Struct-level checks as `(name, expression)` pairs
+    pub checks: Vec<(String, TokenStream)>,
+    pub validators: Vec<TokenStream>,
+    /// `(min, max)` bounds on the number of rows
+    pub rows: Option<(Option<usize>, Option<usize>)>,
+    pub timeseries: Option<TimeSeriesAttrs>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in polars_attrs(attrs) {
+            // `check` and `name` are paired within a single attribute
+            let mut check: Option<(String, TokenStream)> = None;
+            let mut name: Option<LitStr> = None;
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename_all = Some(RenameRule::from_lit(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("check") {
+                    if check.is_some() {
+                        return Err(meta.error("only one `check` per attribute, use another #[polars(...)]"));
+                    }
+                    check = Some(parse_check(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("primary_key") {
+                    let mut key = Vec::new();
+                    meta.parse_nested_meta(|column| match column.path.get_ident() {
+                        Some(ident) => {
+                            key.push(ident.clone());
+                            Ok(())
+                        }
+                        None => Err(column.error("expected a field name")),
+                    })?;
+                    if key.is_empty() {
+                        return Err(meta.error("primary_key must list at least one field"));
+                    }
+                    result.primary_key = Some(key);
+                    Ok(())
+                } else if meta.path.is_ident("validate_with") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.validators.push(parse_validator(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("rows") {
+                    let mut bounds = (None, None);
+                    meta.parse_nested_meta(|bound| {
+                        let value: usize = bound.value()?.parse::<LitInt>()?.base10_parse()?;
+                        if bound.path.is_ident("min") {
+                            bounds.0 = Some(value);
+                        } else if bound.path.is_ident("max") {
+                            bounds.1 = Some(value);
+                        } else {
+                            return Err(bound.error("expected `min` or `max`"));
+                        }
+                        Ok(())
+                    })?;
+                    if bounds == (None, None) {
+                        return Err(meta.error("rows requires `min`, `max` or both"));
+                    }
+                    result.rows = Some(bounds);
+                    Ok(())
+                } else if meta.path.is_ident("timeseries") {
+                    // `PolarsSchema::timeseries` only exists with the `chrono` feature
+                    if !cfg!(feature = "chrono") {
+                        return Err(meta.error("`timeseries` requires the `chrono` feature of polars_schema_validate"));
+                    }
+                    if result.timeseries.is_some() {
+                        return Err(meta.error("only one `timeseries` per struct"));
+                    }
+                    result.timeseries = Some(timeseries::parse_timeseries(&meta)?);
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unknown struct attribute, expected one of `rename_all`, `primary_key`, `check`, \
+                         `name`, `validate_with`, `rows`, `timeseries`",
+                    ))
+                }
+            })?;
+
+            match (check, name) {
+                (Some((default_name, expr)), name) => {
+                    result.checks.push((name.map_or(default_name, |name| name.value()), expr));
+                }
+                (None, Some(name)) => {
+                    return Err(syn::Error::new_spanned(name, "`name` requires a `check` in the same attribute"));
+                }
+                (None, None) => {}
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Field-level `#[polars(...)]` options.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub dtype: Option<TokenStream>,
+    pub dtype_kind: Option<ColumnKind>,
+    pub constraints: Vec<TokenStream>,
+    /// Attributes that only apply to some kinds of columns, checked against the field type
+    pub requirements: Vec<Requirement>,
+    pub unique: bool,
+    pub sort_order: Option<TokenStream>,
+    pub sort_group: Option<LitStr>,
+    pub allow_nan: bool,
+    pub allow_inf: bool,
+    /// Tolerances of the other row-level checks as `(check, tolerance)` pairs, where the
+    /// check is a field of `Tolerances`
+    pub tolerances: Vec<(Ident, TokenStream)>,
+    pub foreign_key: Option<TokenStream>,
+    pub expectations: Vec<TokenStream>,
+    pub validators: Vec<TokenStream>,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in polars_attrs(attrs) {
+            // `mostly` and `max_failures` apply to the constraints and checks of the same attribute
+            let first_constraint = result.constraints.len();
+            let mut tolerance = None;
+            let mut tolerated_checks: Vec<Ident> = Vec::new();
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new_spanned(lit, "column name must not be empty"));
+                    }
+                    result.rename = Some(lit.value());
+                    Ok(())
+                } else if meta.path.is_ident("dtype") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.dtype = Some(parse_dtype(&lit)?);
+                    result.dtype_kind = Some(ColumnKind::of_dtype(&lit.value()));
+                    Ok(())
+                } else if meta.path.is_ident("min") {
+                    let (constraint, kinds) = constraint::parse_min(&meta)?;
+                    result.require(&meta, kinds);
+                    result.constraints.push(constraint);
+                    Ok(())
+                } else if meta.path.is_ident("max") {
+                    let (constraint, kinds) = constraint::parse_max(&meta)?;
+                    result.require(&meta, kinds);
+                    result.constraints.push(constraint);
+                    Ok(())
+                } else if meta.path.is_ident("len") {
+                    result.require(&meta, STRING_OR_LIST);
+                    result.constraints.push(constraint::parse_len(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("regex") {
+                    result.require(&meta, STRING);
+                    result.constraints.push(constraint::parse_regex(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("one_of") {
+                    let (constraint, kinds) = constraint::parse_one_of(&meta)?;
+                    result.require(&meta, kinds);
+                    result.constraints.push(constraint);
+                    Ok(())
+                } else if ["not_null", "not_nan", "finite", "category"].iter().any(|check| meta.path.is_ident(check)) {
+                    tolerated_checks.push(meta.path.require_ident()?.clone());
+                    Ok(())
+                } else if meta.path.is_ident("unique") {
+                    result.unique = true;
+                    Ok(())
+                } else if meta.path.is_ident("sorted") || meta.path.is_ident("strictly_increasing") {
+                    if result.sort_order.is_some() {
+                        return Err(meta.error("only one of `sorted` and `strictly_increasing` per field"));
+                    }
+                    result.sort_order = Some(if meta.path.is_ident("sorted") {
+                        sorted::parse_sorted(&meta)?
+                    } else {
+                        quote!(StrictlyIncreasing)
+                    });
+                    if !tolerated_checks.iter().any(|check| check == "sorted") {
+                        tolerated_checks.push(Ident::new("sorted", meta.path.span()));
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("sorted_within") {
+                    result.sort_group = Some(sorted::parse_sorted_within(&meta)?);
+                    if !tolerated_checks.iter().any(|check| check == "sorted") {
+                        tolerated_checks.push(Ident::new("sorted", meta.path.span()));
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("allow_nan") {
+                    result.allow_nan = true;
+                    Ok(())
+                } else if meta.path.is_ident("allow_inf") {
+                    result.allow_inf = true;
+                    Ok(())
+                } else if meta.path.is_ident("foreign_key") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.foreign_key = Some(parse_foreign_key(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("validate_with") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.validators.push(parse_validator(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("max_null_fraction") {
+                    result.expectations.push(statistic::parse_max_null_fraction(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("distinct") {
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(DistinctCount))?);
+                    Ok(())
+                } else if meta.path.is_ident("mean") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(Mean))?);
+                    Ok(())
+                } else if meta.path.is_ident("sum") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(Sum))?);
+                    Ok(())
+                } else if meta.path.is_ident("std") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(Std))?);
+                    Ok(())
+                } else if meta.path.is_ident("min_value") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(Min))?);
+                    Ok(())
+                } else if meta.path.is_ident("max_value") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_bounds(&meta, quote!(Max))?);
+                    Ok(())
+                } else if meta.path.is_ident("quantile") {
+                    result.require(&meta, NUMERIC);
+                    result.expectations.push(statistic::parse_quantile(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("mostly") || meta.path.is_ident("max_failures") {
+                    if tolerance.is_some() {
+                        return Err(meta.error("only one of `mostly` and `max_failures` per attribute"));
+                    }
+                    tolerance = Some(if meta.path.is_ident("mostly") {
+                        constraint::parse_mostly(&meta)?
+                    } else {
+                        constraint::parse_max_failures(&meta)?
+                    });
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unknown field attribute, expected one of `rename`, `dtype`, `min`, `max`, \
+                         `len`, `regex`, `one_of`, `not_null`, `not_nan`, `finite`, `category`, \
+                         `unique`, `sorted`, `strictly_increasing`, `sorted_within`, `allow_nan`, \
+                         `allow_inf`, `foreign_key`, `validate_with`, `mostly`, `max_failures`, \
+                         `max_null_fraction`, `distinct`, `mean`, `sum`, `std`, `min_value`, \
+                         `max_value`, `quantile`",
+                    ))
+                }
+            })?;
+
+            match tolerance {
+                Some(tolerance) => {
+                    if result.constraints.len() == first_constraint && tolerated_checks.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "`mostly` and `max_failures` require a constraint or check in the same attribute",
+                        ));
+                    }
+                    constraint::with_tolerance(&mut result.constraints[first_constraint..], &tolerance);
+                    for check in tolerated_checks {
+                        if result.tolerances.iter().any(|(other, _)| *other == check) {
+                            return Err(syn::Error::new_spanned(
+                                &check,
+                                format!("`{}` already has a tolerance", check),
+                            ));
+                        }
+                        result.tolerances.push((check, tolerance.clone()));
+                    }
+                }
+                None => {
+                    // The checks are implied by the field, the keywords only set a tolerance
+                    if let Some(check) = tolerated_checks.iter().find(|check| *check != "sorted") {
+                        return Err(syn::Error::new_spanned(
+                            check,
+                            format!("`{}` requires `mostly` or `max_failures` in the same attribute", check),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Allowed values are not checked, so there is nothing to tolerate
+        for (name, allow, allowed) in [("not_nan", "allow_nan", result.allow_nan), ("finite", "allow_inf", result.allow_inf)] {
+            match result.tolerances.iter().find(|(check, _)| check == name) {
+                Some((check, _)) if allowed => {
+                    return Err(syn::Error::new_spanned(check, format!("`{}` cannot be used with `{}`", name, allow)));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Records that the attribute of `meta` only applies to columns of the given kinds.
+    fn require(&mut self, meta: &ParseNestedMeta, kinds: &'static [ColumnKind]) {
+        self.requirements.push(Requirement {
+            attribute: meta.path.to_token_stream().to_string(),
+            kinds,
+            span: meta.path.span(),
+        });
+    }
+
+    /// Kind of the column, from the dtype override or else from the Rust field type.
+    pub fn column_kind(&self, ty: &Type) -> ColumnKind {
+        self.dtype_kind.unwrap_or_else(|| ColumnKind::of_type(ty))
+    }
+}
+
+/// Enum-level `#[polars(...)]` options for `#[derive(PolarsEnum)]`.
+#[derive(Default)]
+pub struct EnumAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub categorical: bool,
+}
+
+impl EnumAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in polars_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename_all = Some(RenameRule::from_lit(&lit)?);
+                    Ok(())
+                } else if meta.path.is_ident("categorical") {
+                    result.categorical = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown enum attribute, expected `rename_all` or `categorical`"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Variant-level `#[polars(...)]` options for `#[derive(PolarsEnum)]`.
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut result = Self::default();
+
+        for attr in polars_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    result.rename = Some(lit.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown variant attribute, expected `rename`"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parses `check = "expr"` or `check = path::to::fn` into its default name and a
+/// Polars `Expr` expression.
+///
+/// String expressions are evaluated with `polars::prelude::*` in scope, functions must
+/// have the signature `fn() -> Expr`.
+fn parse_check(meta: &ParseNestedMeta) -> syn::Result<(String, TokenStream)> {
+    let value = meta.value()?;
+    if value.peek(LitStr) {
+        let lit: LitStr = value.parse()?;
+        let expr: Expr = lit.parse()?;
+        Ok((lit.value(), quote!({
+            #[allow(unused_imports)]
+            use ::polars::prelude::*;
+            #expr
+        })))
+    } else {
+        let path: Path = value.parse()?;
+        let name = quote!(#path).to_string().replace(' ', "");
+        Ok((name, quote!(#path())))
+    }
+}
+
+/// Parses `validate_with = "path::to::fn"` into a `Validator` expression.
+///
+/// The function's signature is checked by `Validator::new`: `fn(&Series) -> Result<()>`
+/// on fields, `fn(&DataFrame) -> Result<()>` on structs.
+fn parse_validator(lit: &LitStr) -> syn::Result<TokenStream> {
+    let path: Path = lit.parse()?;
+    let name = lit.value();
+    Ok(quote!(::polars_schema_validate::Validator::new(#name, #path)))
+}
+
+/// Parses `#[polars(foreign_key = "Customer::id")]` into a `ForeignKey` expression.
+///
+/// Everything before the last segment is the referenced schema type, the last segment is
+/// the referenced column name.
+fn parse_foreign_key(lit: &LitStr) -> syn::Result<TokenStream> {
+    let error = || {
+        syn::Error::new_spanned(
+            lit,
+            "invalid foreign_key, expected \"Type::column\", e.g. \"Customer::id\"",
+        )
+    };
+
+    let mut path: Path = lit.parse().map_err(|_| error())?;
+    let column = match path.segments.pop() {
+        Some(column) if !path.segments.is_empty() && column.value().arguments.is_none() => {
+            column.into_value().ident.unraw().to_string()
+        }
+        _ => return Err(error()),
+    };
+    // Drop the trailing `::` left behind by `pop`
+    let last = path.segments.pop().unwrap().into_value();
+    path.segments.push(last);
+
+    let table = quote!(#path).to_string().replace(' ', "");
+    Ok(quote! {
+        Some(::polars_schema_validate::ForeignKey {
+            table: #table,
+            type_id: ::std::any::TypeId::of::<#path>(),
+            column: #column,
+        })
+    })
+}
+
+fn polars_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("polars"))
+}
@@ -0,0 +1,139 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::{parenthesized, Lit, LitFloat, LitInt, LitStr, Token};
+
+use crate::kind::{ColumnKind, NUMERIC, STRING};
+
+/// Parses a literal into a `ConstraintValue` expression: an integer, a float (both
+/// optionally negative) or a string, with the kinds of columns it can be compared to.
+pub fn parse_value(input: ParseStream) -> syn::Result<(TokenStream, &'static [ColumnKind])> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let lit: Lit = input.parse()?;
+
+    let (value, kinds) = match &lit {
+        Lit::Int(int) => {
+            let value: i64 = int.base10_parse()?;
+            let value = if negative { -value } else { value };
+            (quote!(Int(#value)), NUMERIC)
+        }
+        Lit::Float(float) => {
+            let value: f64 = float.base10_parse()?;
+            let value = if negative { -value } else { value };
+            (quote!(Float(#value)), NUMERIC)
+        }
+        // Polars does not compare temporal columns to strings
+        Lit::Str(string) if !negative => (quote!(Str(#string)), STRING),
+        _ => return Err(syn::Error::new_spanned(lit, "expected an integer, float or string literal")),
+    };
+
+    Ok((quote!(::polars_schema_validate::ConstraintValue::#value), kinds))
+}
+
+/// Parses `min = ...` into a `Constraint::Min`, with the kinds of columns it applies to.
+pub fn parse_min(meta: &ParseNestedMeta) -> syn::Result<(TokenStream, &'static [ColumnKind])> {
+    let (value, kinds) = parse_value(meta.value()?)?;
+    Ok((quote!(::polars_schema_validate::Constraint::Min(#value)), kinds))
+}
+
+/// Parses `max = ...` into a `Constraint::Max`, with the kinds of columns it applies to.
+pub fn parse_max(meta: &ParseNestedMeta) -> syn::Result<(TokenStream, &'static [ColumnKind])> {
+    let (value, kinds) = parse_value(meta.value()?)?;
+    Ok((quote!(::polars_schema_validate::Constraint::Max(#value)), kinds))
+}
+
+/// Parses `len(min = ..., max = ...)` into a `Constraint::Length`.
+pub fn parse_len(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let mut min = None;
+    let mut max = None;
+
+    meta.parse_nested_meta(|bound| {
+        let value: usize = bound.value()?.parse::<LitInt>()?.base10_parse()?;
+        if bound.path.is_ident("min") {
+            min = Some(value);
+        } else if bound.path.is_ident("max") {
+            max = Some(value);
+        } else {
+            return Err(bound.error("expected `min` or `max`"));
+        }
+        Ok(())
+    })?;
+
+    if min.is_none() && max.is_none() {
+        return Err(meta.error("len requires `min`, `max` or both"));
+    }
+    let min = option_tokens(min);
+    let max = option_tokens(max);
+    Ok(quote!(::polars_schema_validate::Constraint::Length { min: #min, max: #max }))
+}
+
+/// Parses `regex = "..."` into a `Constraint::Regex`, compiling the pattern so an
+/// invalid one is reported at compile time.
+pub fn parse_regex(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let pattern: LitStr = meta.value()?.parse()?;
+    if let Err(err) = regex::Regex::new(&pattern.value()) {
+        return Err(syn::Error::new_spanned(pattern, format!("invalid regex: {}", err)));
+    }
+    Ok(quote!(::polars_schema_validate::Constraint::Regex(#pattern)))
+}
+
+/// Parses `one_of(value, ...)` into a `Constraint::OneOf`, with the kinds of columns it
+/// applies to. All values must be of the same kind, numbers or strings.
+pub fn parse_one_of(meta: &ParseNestedMeta) -> syn::Result<(TokenStream, &'static [ColumnKind])> {
+    let content;
+    parenthesized!(content in meta.input);
+
+    let mut values = Vec::new();
+    let mut kinds: Option<&'static [ColumnKind]> = None;
+    while !content.is_empty() {
+        let span = content.span();
+        let (value, value_kinds) = parse_value(&content)?;
+        if kinds.is_some_and(|kinds| kinds != value_kinds) {
+            return Err(syn::Error::new(span, "one_of values must all be numbers or all be strings"));
+        }
+        kinds = Some(value_kinds);
+        values.push(value);
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+
+    let Some(kinds) = kinds else {
+        return Err(meta.error("one_of requires at least one value"));
+    };
+    Ok((quote!(::polars_schema_validate::Constraint::OneOf(vec![#(#values),*])), kinds))
+}
+
+/// Parses `mostly = 0.995` into a `Tolerance::Mostly`.
+pub fn parse_mostly(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let lit: LitFloat = meta.value()?.parse()?;
+    let fraction: f64 = lit.base10_parse()?;
+    if !(fraction > 0.0 && fraction <= 1.0) {
+        return Err(syn::Error::new_spanned(lit, "mostly must be a fraction in (0, 1]"));
+    }
+    Ok(quote!(::polars_schema_validate::Tolerance::Mostly(#fraction)))
+}
+
+/// Parses `max_failures = 10` into a `Tolerance::MaxFailures`.
+pub fn parse_max_failures(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let max_failures: usize = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+    Ok(quote!(::polars_schema_validate::Tolerance::MaxFailures(#max_failures)))
+}
+
+/// Wraps constraints into `Constraint::Tolerant` with the given tolerance.
+pub fn with_tolerance(constraints: &mut [TokenStream], tolerance: &TokenStream) {
+    for constraint in constraints {
+        *constraint = quote! {
+            ::polars_schema_validate::Constraint::Tolerant(::std::boxed::Box::new(#constraint), #tolerance)
+        };
+    }
+}
+
+pub fn option_tokens(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
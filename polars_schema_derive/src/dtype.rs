@@ -0,0 +1,203 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+const SUPPORTED_DTYPES: &str = "Boolean, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, \
+    Float32, Float64, String, Binary, Date, Time, Datetime(unit[, time_zone]), Duration(unit), \
+    Categorical, Categorical(physical|lexical), List(dtype), Array(dtype, width)";
+
+/// A parsed dtype expression such as `Datetime(ms, UTC)`: a name with optional arguments.
+struct Node {
+    name: String,
+    args: Vec<Node>,
+}
+
+/// Parses a `#[polars(dtype = "...")]` string into a `DataType` expression.
+pub fn parse_dtype(lit: &LitStr) -> syn::Result<TokenStream> {
+    let value = lit.value();
+    let error = |message: String| {
+        syn::Error::new_spanned(
+            lit,
+            format!("invalid dtype \"{}\": {}; supported dtypes are: {}", value, message, SUPPORTED_DTYPES),
+        )
+    };
+
+    let mut parser = Parser { input: &value, pos: 0 };
+    let node = parser.parse_node().map_err(&error)?;
+    parser.skip_whitespace();
+    if parser.pos != value.len() {
+        return Err(error(format!("unexpected trailing input \"{}\"", &value[parser.pos..])));
+    }
+
+    node_to_dtype(&node).map_err(error)
+}
+
+fn node_to_dtype(node: &Node) -> Result<TokenStream, String> {
+    let expect_args = |count: std::ops::RangeInclusive<usize>| {
+        if count.contains(&node.args.len()) {
+            Ok(())
+        } else {
+            Err(format!("wrong number of arguments for {}", node.name))
+        }
+    };
+
+    let dtype = match node.name.as_str() {
+        "Boolean" | "Bool" => quote!(Boolean),
+        "Int8" => quote!(Int8),
+        "Int16" => quote!(Int16),
+        "Int32" => quote!(Int32),
+        "Int64" => quote!(Int64),
+        "UInt8" => quote!(UInt8),
+        "UInt16" => quote!(UInt16),
+        "UInt32" => quote!(UInt32),
+        "UInt64" => quote!(UInt64),
+        "Float32" => quote!(Float32),
+        "Float64" => quote!(Float64),
+        "String" | "Utf8" | "Str" => quote!(String),
+        "Binary" => quote!(Binary),
+        "Date" => quote!(Date),
+        "Time" => quote!(Time),
+        "Datetime" => {
+            expect_args(0..=2)?;
+            let unit = match node.args.first() {
+                Some(arg) => time_unit(arg)?,
+                None => quote!(::polars::prelude::TimeUnit::Microseconds),
+            };
+            let time_zone = match node.args.get(1) {
+                Some(arg) if !arg.args.is_empty() => return Err(format!("invalid time zone {}", arg.name)),
+                Some(arg) if !is_time_zone(&arg.name) => {
+                    return Err(format!("unknown time zone {}, expected an IANA name or an offset such as +01:00", arg.name))
+                }
+                Some(arg) => {
+                    let tz = &arg.name;
+                    quote! {
+                        ::polars::prelude::TimeZone::opt_try_new(Some(#tz))
+                            .expect("time zone checked by #[derive(PolarsSchema)]")
+                    }
+                }
+                None => quote!(None),
+            };
+            quote!(Datetime(#unit, #time_zone))
+        }
+        "Duration" => {
+            expect_args(0..=1)?;
+            let unit = match node.args.first() {
+                Some(arg) => time_unit(arg)?,
+                None => quote!(::polars::prelude::TimeUnit::Microseconds),
+            };
+            quote!(Duration(#unit))
+        }
+        "Categorical" => {
+            expect_args(0..=1)?;
+            let ordering = match node.args.first().map(|arg| arg.name.as_str()) {
+                None | Some("physical") => quote!(::polars::prelude::CategoricalOrdering::Physical),
+                Some("lexical") => quote!(::polars::prelude::CategoricalOrdering::Lexical),
+                Some(other) => return Err(format!("unknown categorical ordering {}", other)),
+            };
+            quote!(Categorical(None, #ordering))
+        }
+        "List" => {
+            expect_args(1..=1)?;
+            let inner = node_to_dtype(&node.args[0])?;
+            quote!(List(Box::new(#inner)))
+        }
+        "Array" => {
+            expect_args(2..=2)?;
+            let inner = node_to_dtype(&node.args[0])?;
+            let width_node = &node.args[1];
+            let width: usize = match width_node.name.parse() {
+                Ok(width) if width_node.args.is_empty() && width > 0 => width,
+                _ => return Err(format!("invalid array width {}", width_node.name)),
+            };
+            quote!(Array(Box::new(#inner), #width))
+        }
+        other => return Err(format!("unknown dtype {}", other)),
+    };
+
+    let takes_args = matches!(
+        node.name.as_str(),
+        "Datetime" | "Duration" | "Categorical" | "List" | "Array"
+    );
+    if !takes_args && !node.args.is_empty() {
+        return Err(format!("{} does not take arguments", node.name));
+    }
+
+    Ok(quote!(::polars::prelude::DataType::#dtype))
+}
+
+fn time_unit(node: &Node) -> Result<TokenStream, String> {
+    if !node.args.is_empty() {
+        return Err(format!("invalid time unit {}", node.name));
+    }
+    match node.name.as_str() {
+        "ns" | "Nanoseconds" => Ok(quote!(::polars::prelude::TimeUnit::Nanoseconds)),
+        "us" | "μs" | "Microseconds" => Ok(quote!(::polars::prelude::TimeUnit::Microseconds)),
+        "ms" | "Milliseconds" => Ok(quote!(::polars::prelude::TimeUnit::Milliseconds)),
+        other => Err(format!("unknown time unit {}, expected ns, us or ms", other)),
+    }
+}
+
+/// Returns `true` for the time zones Polars accepts: IANA names such as `Europe/Paris`
+/// and whole-hour offsets such as `+01:00`.
+fn is_time_zone(tz: &str) -> bool {
+    if tz.parse::<chrono_tz::Tz>().is_ok() {
+        return true;
+    }
+    let offset = tz.strip_prefix(['+', '-']).unwrap_or(tz);
+    let hour = match offset.strip_suffix("00") {
+        Some(hour) => hour.strip_suffix(':').unwrap_or(hour),
+        None => return false,
+    };
+    hour.len() == 2 && hour.parse::<u8>().is_ok_and(|hour| hour <= 14)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn parse_node(&mut self) -> Result<Node, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if matches!(c, '(' | ')' | ',') {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        let name = self.input[start..self.pos].trim().to_string();
+        if name.is_empty() {
+            return Err("expected a dtype name".to_string());
+        }
+
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            loop {
+                args.push(self.parse_node()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.pos += 1,
+                    Some(')') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(format!("missing closing parenthesis after {}", name)),
+                }
+            }
+        }
+
+        Ok(Node { name, args })
+    }
+}
@@ -0,0 +1,100 @@
+use proc_macro2::Span;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Kind of column a field maps to, used to reject attributes that do not apply to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Numeric,
+    String,
+    Temporal,
+    Boolean,
+    List,
+    Other,
+    /// Types resolved through `PolarsDtype` only, such as nested structs, enums and
+    /// custom types; attributes are checked against their dtype at runtime
+    Unknown,
+}
+
+pub const NUMERIC: &[ColumnKind] = &[ColumnKind::Numeric];
+pub const STRING: &[ColumnKind] = &[ColumnKind::String];
+pub const STRING_OR_LIST: &[ColumnKind] = &[ColumnKind::String, ColumnKind::List];
+
+impl ColumnKind {
+    /// Classifies a Rust field type, looking through `Option`.
+    pub fn of_type(ty: &Type) -> Self {
+        match ty {
+            Type::Reference(reference) => Self::of_type(&reference.elem),
+            Type::Paren(paren) => Self::of_type(&paren.elem),
+            Type::Group(group) => Self::of_type(&group.elem),
+            Type::Array(_) => ColumnKind::List,
+            Type::Path(path) if path.qself.is_none() => {
+                let Some(segment) = path.path.segments.last() else {
+                    return ColumnKind::Unknown;
+                };
+                match segment.ident.to_string().as_str() {
+                    "Option" => match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => match args.args.first() {
+                            Some(GenericArgument::Type(inner)) => Self::of_type(inner),
+                            _ => ColumnKind::Unknown,
+                        },
+                        _ => ColumnKind::Unknown,
+                    },
+                    "Vec" => ColumnKind::List,
+                    "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" => {
+                        ColumnKind::Numeric
+                    }
+                    "String" | "str" => ColumnKind::String,
+                    "bool" => ColumnKind::Boolean,
+                    "NaiveDate" | "NaiveDateTime" | "NaiveTime" | "DateTime" => ColumnKind::Temporal,
+                    _ => ColumnKind::Unknown,
+                }
+            }
+            _ => ColumnKind::Unknown,
+        }
+    }
+
+    /// Classifies a `#[polars(dtype = "...")]` override by its dtype name.
+    pub fn of_dtype(dtype: &str) -> Self {
+        let name = dtype.split('(').next().unwrap_or_default().trim();
+        match name {
+            "Int8" | "Int16" | "Int32" | "Int64" | "UInt8" | "UInt16" | "UInt32" | "UInt64" | "Float32"
+            | "Float64" => ColumnKind::Numeric,
+            "String" | "Utf8" | "Str" => ColumnKind::String,
+            "Date" | "Time" | "Datetime" | "Duration" => ColumnKind::Temporal,
+            "Boolean" | "Bool" => ColumnKind::Boolean,
+            "List" | "Array" => ColumnKind::List,
+            _ => ColumnKind::Other,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ColumnKind::Numeric => "a numeric",
+            ColumnKind::String => "a string",
+            ColumnKind::Temporal => "a temporal",
+            ColumnKind::Boolean => "a boolean",
+            ColumnKind::List => "a list",
+            ColumnKind::Other | ColumnKind::Unknown => "this",
+        }
+    }
+}
+
+/// An attribute that only applies to some kinds of columns, e.g. `regex` to strings.
+pub struct Requirement {
+    pub attribute: String,
+    pub kinds: &'static [ColumnKind],
+    pub span: Span,
+}
+
+impl Requirement {
+    /// Fails with an error at the attribute if it does not apply to a column of `kind`.
+    pub fn check(&self, kind: ColumnKind) -> syn::Result<()> {
+        if kind == ColumnKind::Unknown || self.kinds.contains(&kind) {
+            return Ok(());
+        }
+        Err(syn::Error::new(
+            self.span,
+            format!("`{}` cannot be used on {} column", self.attribute, kind.description()),
+        ))
+    }
+}
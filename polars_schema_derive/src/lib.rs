@@ -1,83 +1,271 @@
+use std::collections::{HashMap, HashSet};
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+mod attr;
+mod case;
+mod constraint;
+mod dtype;
+mod kind;
+mod polars_enum;
+mod sorted;
+mod statistic;
+mod timeseries;
+
+use attr::{ContainerAttrs, FieldAttrs};
 
-#[proc_macro_derive(PolarsSchema)]
+#[proc_macro_derive(PolarsSchema, attributes(polars))]
 pub fn derive_polars_schema(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_polars_schema(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+#[proc_macro_derive(PolarsEnum, attributes(polars))]
+pub fn derive_polars_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match polars_enum::expand_polars_enum(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn expand_polars_schema(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
-    
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
     let fields = match &input.data {
         Data::Struct(data) => match &data.fields {
             Fields::Named(fields) => &fields.named,
-            _ => panic!("PolarsSchema only supports structs with named fields"),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "PolarsSchema only supports structs with named fields",
+                ))
+            }
         },
-        _ => panic!("PolarsSchema can only be derived for structs"),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "PolarsSchema can only be derived for structs",
+            ))
+        }
     };
-    
-    let schema_entries = fields.iter().map(|field| {
-        let field_name = field.ident.as_ref().unwrap().to_string();
+
+    let container_attrs = ContainerAttrs::parse(&input.attrs)?;
+    let mut column_names = HashSet::new();
+    let mut columns_by_ident = HashMap::new();
+    let mut field_specs = Vec::with_capacity(fields.len());
+    let mut sort_groups = Vec::new();
+
+    for field in fields {
+        let field_attrs = FieldAttrs::parse(&field.attrs)?;
+        let ident = field.ident.as_ref().unwrap().unraw().to_string();
+        let field_name = match (field_attrs.rename.clone(), container_attrs.rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply_to_field(&ident),
+            (None, None) => ident.clone(),
+        };
+        columns_by_ident.insert(ident, field_name.clone());
+        if !column_names.insert(field_name.clone()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("duplicate column name \"{}\"", field_name),
+            ));
+        }
+
         let field_type = &field.ty;
-        let dtype = type_to_polars_dtype(field_type);
-        
-        quote! {
-            (#field_name, #dtype)
+        let kind = field_attrs.column_kind(field_type);
+        for requirement in &field_attrs.requirements {
+            requirement.check(kind)?;
         }
-    });
-    
-    let expanded = quote! {
-        impl PolarsSchema for #name {
-            fn schema() -> Vec<(&'static str, ::polars::prelude::DataType)> {
-                vec![
-                    #(#schema_entries),*
-                ]
+        // Spanned on the field type so an unsupported type is reported right there
+        let dtype_impl = quote_spanned! {field_type.span()=>
+            <#field_type as ::polars_schema_validate::PolarsDtype>
+        };
+
+        let constraints = &field_attrs.constraints;
+        let unique = field_attrs.unique;
+        let sorted = sorted::sortedness(field_attrs.sort_order.as_ref(), field_attrs.sort_group.as_ref());
+        sort_groups.extend(field_attrs.sort_group.clone());
+        let allow_nan = field_attrs.allow_nan;
+        let allow_inf = field_attrs.allow_inf;
+        let (tolerated_checks, tolerances): (Vec<_>, Vec<_>) = field_attrs.tolerances.iter().cloned().unzip();
+        let foreign_key = field_attrs.foreign_key.clone().unwrap_or_else(|| quote!(None));
+        let expectations = &field_attrs.expectations;
+        let validators = &field_attrs.validators;
+
+        // An explicit dtype replaces the nested fields of the Rust type as well
+        let (dtype, nested_fields) = match &field_attrs.dtype {
+            Some(dtype) => (dtype.clone(), quote!(Vec::new())),
+            None => (quote!(#dtype_impl::dtype()), quote!(#dtype_impl::nested_fields())),
+        };
+
+        field_specs.push(quote! {
+            ::polars_schema_validate::FieldSpec {
+                nullable: #dtype_impl::NULLABLE,
+                fields: #nested_fields,
+                items: #dtype_impl::items().map(Box::new),
+                allow_nan: #allow_nan,
+                allow_inf: #allow_inf,
+                categories: #dtype_impl::categories(),
+                constraints: vec![#(#constraints),*],
+                tolerances: ::polars_schema_validate::Tolerances {
+                    #(#tolerated_checks: Some(#tolerances),)*
+                    ..::std::default::Default::default()
+                },
+                unique: #unique,
+                sorted: #sorted,
+                foreign_key: #foreign_key,
+                expectations: vec![#(#expectations),*],
+                validators: vec![#(#validators),*],
+                ..::polars_schema_validate::FieldSpec::new(#field_name, #dtype)
+            }
+        });
+    }
+
+    // Groups name columns, so they are resolved after all fields are renamed
+    for group in &sort_groups {
+        if !column_names.contains(&group.value()) {
+            return Err(syn::Error::new_spanned(
+                group,
+                format!("unknown column \"{}\" in sorted_within", group.value()),
+            ));
+        }
+    }
+    if let Some(timeseries) = &container_attrs.timeseries {
+        for column in timeseries.columns() {
+            if !column_names.contains(&column.value()) {
+                return Err(syn::Error::new_spanned(
+                    column,
+                    format!("unknown column \"{}\" in timeseries", column.value()),
+                ));
             }
         }
+    }
+
+    let primary_key = match &container_attrs.primary_key {
+        Some(key) => {
+            let mut key_columns = Vec::with_capacity(key.len());
+            for ident in key {
+                match columns_by_ident.get(&ident.unraw().to_string()) {
+                    Some(column) if key_columns.contains(column) => {
+                        return Err(syn::Error::new_spanned(ident, "duplicate field in primary_key"))
+                    }
+                    Some(column) => key_columns.push(column.clone()),
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            format!("unknown field `{}` in primary_key", ident),
+                        ))
+                    }
+                }
+            }
+            quote! {
+                fn primary_key() -> Option<Vec<&'static str>> {
+                    Some(vec![#(#key_columns),*])
+                }
+            }
+        }
+        None => quote!(),
     };
-    
-    TokenStream::from(expanded)
-}
 
-fn type_to_polars_dtype(ty: &Type) -> proc_macro2::TokenStream {
-    let type_str = quote!(#ty).to_string();
-    
-    match type_str.as_str() {
-        "i8" => quote!(::polars::prelude::DataType::Int8),
-        "i16" => quote!(::polars::prelude::DataType::Int16),
-        "i32" => quote!(::polars::prelude::DataType::Int32),
-        "i64" => quote!(::polars::prelude::DataType::Int64),
-        "u8" => quote!(::polars::prelude::DataType::UInt8),
-        "u16" => quote!(::polars::prelude::DataType::UInt16),
-        "u32" => quote!(::polars::prelude::DataType::UInt32),
-        "u64" => quote!(::polars::prelude::DataType::UInt64),
-        "f32" => quote!(::polars::prelude::DataType::Float32),
-        "f64" => quote!(::polars::prelude::DataType::Float64),
-        "bool" => quote!(::polars::prelude::DataType::Boolean),
-        "String" => quote!(::polars::prelude::DataType::String),
-        "& str" | "&str" => quote!(::polars::prelude::DataType::String),
-        
-        // Chrono types support
-        #[cfg(feature = "chrono")]
-        "NaiveDate" | "chrono :: NaiveDate" => {
-            quote!(::polars::prelude::DataType::Date)
+    let checks = if container_attrs.checks.is_empty() {
+        quote!()
+    } else {
+        let checks = container_attrs.checks.iter().map(|(name, expr)| {
+            quote!(::polars_schema_validate::Check::new(#name, #expr))
+        });
+        quote! {
+            fn checks() -> Vec<::polars_schema_validate::Check> {
+                vec![#(#checks),*]
+            }
         }
-        #[cfg(feature = "chrono")]
-        "NaiveDateTime" | "chrono :: NaiveDateTime" => {
-            quote!(::polars::prelude::DataType::Datetime(::polars::prelude::TimeUnit::Microseconds, None))
+    };
+
+    let validators = if container_attrs.validators.is_empty() {
+        quote!()
+    } else {
+        let validators = &container_attrs.validators;
+        quote! {
+            fn validators() -> Vec<::polars_schema_validate::Validator<::polars::prelude::DataFrame>> {
+                vec![#(#validators),*]
+            }
         }
-        #[cfg(feature = "chrono")]
-        "NaiveTime" | "chrono :: NaiveTime" => {
-            quote!(::polars::prelude::DataType::Time)
+    };
+
+    let row_bounds = match container_attrs.rows {
+        Some((min, max)) => {
+            let min = constraint::option_tokens(min);
+            let max = constraint::option_tokens(max);
+            quote! {
+                fn row_bounds() -> (Option<usize>, Option<usize>) {
+                    (#min, #max)
+                }
+            }
+        }
+        None => quote!(),
+    };
+
+    let timeseries = match &container_attrs.timeseries {
+        Some(timeseries) => {
+            let timeseries = timeseries.to_tokens();
+            quote! {
+                fn timeseries() -> Option<::polars_schema_validate::TimeSeries> {
+                    Some(#timeseries)
+                }
+            }
         }
-        #[cfg(feature = "chrono")]
-        s if s.contains("DateTime") && s.contains("Utc") => {
-            quote!(::polars::prelude::DataType::Datetime(::polars::prelude::TimeUnit::Microseconds, Some("UTC".into())))
+        None => quote!(),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::polars_schema_validate::PolarsSchema for #name #ty_generics #where_clause {
+            fn schema() -> Vec<(&'static str, ::polars::prelude::DataType)> {
+                Self::fields()
+                    .into_iter()
+                    .map(|field| (field.name, field.dtype))
+                    .collect()
+            }
+
+            fn fields() -> Vec<::polars_schema_validate::FieldSpec> {
+                vec![
+                    #(#field_specs),*
+                ]
+            }
+
+            #primary_key
+
+            #checks
+
+            #validators
+
+            #row_bounds
+
+            #timeseries
         }
-        
-        s if s.starts_with("Option <") => {
-            let inner = s.trim_start_matches("Option <").trim_end_matches('>').trim();
-            type_to_polars_dtype(&syn::parse_str::<Type>(inner).unwrap())
+
+        impl #impl_generics ::polars_schema_validate::PolarsDtype for #name #ty_generics #where_clause {
+            fn dtype() -> ::polars::prelude::DataType {
+                ::polars::prelude::DataType::Struct(
+                    <Self as ::polars_schema_validate::PolarsSchema>::fields()
+                        .iter()
+                        .map(::polars_schema_validate::FieldSpec::to_field)
+                        .collect(),
+                )
+            }
+
+            fn nested_fields() -> Vec<::polars_schema_validate::FieldSpec> {
+                <Self as ::polars_schema_validate::PolarsSchema>::fields()
+            }
         }
-        _ => quote!(::polars::prelude::DataType::String),
-    }
-}
\ No newline at end of file
+    })
+}
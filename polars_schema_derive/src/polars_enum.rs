@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attr::{EnumAttrs, VariantAttrs};
+
+pub fn expand_polars_enum(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PolarsEnum can only be derived for enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PolarsEnum requires at least one variant",
+        ));
+    }
+
+    let enum_attrs = EnumAttrs::parse(&input.attrs)?;
+    let mut categories = Vec::with_capacity(data.variants.len());
+    let mut seen = HashSet::new();
+    let mut match_arms = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "PolarsEnum only supports unit variants",
+            ));
+        }
+
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+        let ident = variant.ident.unraw().to_string();
+        let category = match (variant_attrs.rename, enum_attrs.rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply_to_variant(&ident),
+            (None, None) => ident,
+        };
+        if !seen.insert(category.clone()) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("duplicate category \"{}\"", category),
+            ));
+        }
+
+        let variant_ident = &variant.ident;
+        match_arms.push(quote!(Self::#variant_ident => #category));
+        categories.push(category);
+    }
+
+    let dtype = if enum_attrs.categorical {
+        quote! {
+            ::polars::prelude::DataType::Categorical(None, ::std::default::Default::default())
+        }
+    } else {
+        quote! {
+            ::polars_schema_validate::enum_dtype(<Self as ::polars_schema_validate::PolarsEnum>::VARIANTS)
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::polars_schema_validate::PolarsEnum for #name #ty_generics #where_clause {
+            const VARIANTS: &'static [&'static str] = &[#(#categories),*];
+
+            fn as_str(&self) -> &'static str {
+                match self {
+                    #(#match_arms),*
+                }
+            }
+        }
+
+        impl #impl_generics ::polars_schema_validate::PolarsDtype for #name #ty_generics #where_clause {
+            fn dtype() -> ::polars::prelude::DataType {
+                #dtype
+            }
+
+            fn categories() -> Option<Vec<&'static str>> {
+                Some(<Self as ::polars_schema_validate::PolarsEnum>::VARIANTS.to_vec())
+            }
+        }
+    })
+}
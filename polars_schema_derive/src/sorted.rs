@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::LitStr;
+
+/// Parses `sorted = "asc"` or `sorted = "desc"` into a `SortOrder` variant.
+pub fn parse_sorted(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let lit: LitStr = meta.value()?.parse()?;
+    match lit.value().as_str() {
+        "asc" => Ok(quote!(Ascending)),
+        "desc" => Ok(quote!(Descending)),
+        _ => Err(syn::Error::new_spanned(lit, "expected \"asc\" or \"desc\"")),
+    }
+}
+
+/// Parses `sorted_within(group = "column")` into the group column name.
+pub fn parse_sorted_within(meta: &ParseNestedMeta) -> syn::Result<LitStr> {
+    let mut group = None;
+
+    meta.parse_nested_meta(|option| {
+        if option.path.is_ident("group") {
+            let lit: LitStr = option.value()?.parse()?;
+            if lit.value().is_empty() {
+                return Err(syn::Error::new_spanned(lit, "column name must not be empty"));
+            }
+            group = Some(lit);
+            Ok(())
+        } else {
+            Err(option.error("expected `group`"))
+        }
+    })?;
+
+    group.ok_or_else(|| meta.error("sorted_within requires `group = \"column\"`"))
+}
+
+/// Builds the `Sortedness` of a field, ascending unless another order is declared.
+pub fn sortedness(order: Option<&TokenStream>, group: Option<&LitStr>) -> TokenStream {
+    if order.is_none() && group.is_none() {
+        return quote!(None);
+    }
+    let order = order.cloned().unwrap_or_else(|| quote!(Ascending));
+    let group = match group {
+        Some(group) => quote!(Some(#group)),
+        None => quote!(None),
+    };
+    quote! {
+        Some(::polars_schema_validate::Sortedness {
+            order: ::polars_schema_validate::SortOrder::#order,
+            group: #group,
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::{parenthesized, Lit, Token};
+
+/// Parses an integer or float literal, optionally negative, as an `f64` bound.
+fn parse_f64(input: ParseStream) -> syn::Result<f64> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let value = match input.parse::<Lit>()? {
+        Lit::Int(int) => int.base10_parse::<i64>()? as f64,
+        Lit::Float(float) => float.base10_parse()?,
+        lit => return Err(syn::Error::new_spanned(lit, "expected an integer or float literal")),
+    };
+    Ok(if negative { -value } else { value })
+}
+
+fn expectation(statistic: TokenStream, min: Option<f64>, max: Option<f64>) -> TokenStream {
+    let min = option_tokens(min);
+    let max = option_tokens(max);
+    quote! {
+        ::polars_schema_validate::Expectation::new(
+            ::polars_schema_validate::Statistic::#statistic,
+            #min,
+            #max,
+        )
+    }
+}
+
+/// Parses `max_null_fraction = 0.1` into a `NullFraction` expectation.
+pub fn parse_max_null_fraction(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let max = parse_f64(meta.value()?)?;
+    if !(0.0..=1.0).contains(&max) {
+        return Err(meta.error("max_null_fraction must be a fraction in [0, 1]"));
+    }
+    Ok(expectation(quote!(NullFraction), None, Some(max)))
+}
+
+/// Parses `<statistic>(min = ..., max = ...)` into an expectation on the statistic.
+pub fn parse_bounds(meta: &ParseNestedMeta, statistic: TokenStream) -> syn::Result<TokenStream> {
+    let mut min = None;
+    let mut max = None;
+
+    meta.parse_nested_meta(|bound| {
+        let value = parse_f64(bound.value()?)?;
+        if bound.path.is_ident("min") {
+            min = Some(value);
+        } else if bound.path.is_ident("max") {
+            max = Some(value);
+        } else {
+            return Err(bound.error("expected `min` or `max`"));
+        }
+        Ok(())
+    })?;
+
+    if min.is_none() && max.is_none() {
+        return Err(meta.error("expected `min`, `max` or both"));
+    }
+    Ok(expectation(statistic, min, max))
+}
+
+/// Parses `quantile(0.99, min = ..., max = ...)` into a `Quantile` expectation.
+pub fn parse_quantile(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let content;
+    parenthesized!(content in meta.input);
+
+    let quantile = parse_f64(&content)?;
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(meta.error("quantile must be in [0, 1]"));
+    }
+
+    let mut min = None;
+    let mut max = None;
+    while !content.is_empty() {
+        content.parse::<Token![,]>()?;
+        if content.is_empty() {
+            break;
+        }
+        let bound: syn::Ident = content.parse()?;
+        content.parse::<Token![=]>()?;
+        let value = parse_f64(&content)?;
+        if bound == "min" {
+            min = Some(value);
+        } else if bound == "max" {
+            max = Some(value);
+        } else {
+            return Err(syn::Error::new_spanned(bound, "expected `min` or `max`"));
+        }
+    }
+
+    if min.is_none() && max.is_none() {
+        return Err(meta.error("quantile requires `min`, `max` or both"));
+    }
+    Ok(expectation(quote!(Quantile(#quantile)), min, max))
+}
+
+fn option_tokens(value: Option<f64>) -> TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
@@ -0,0 +1,126 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::LitStr;
+
+/// Struct-level `#[polars(timeseries(...))]` options.
+pub struct TimeSeriesAttrs {
+    pub time: LitStr,
+    pub by: Option<LitStr>,
+    /// Grid interval in nanoseconds
+    pub every: Option<u64>,
+    /// Longest allowed gap in nanoseconds
+    pub max_gap: Option<u64>,
+}
+
+impl TimeSeriesAttrs {
+    /// Column names referenced by the time series, to be resolved against the fields.
+    pub fn columns(&self) -> impl Iterator<Item = &LitStr> {
+        std::iter::once(&self.time).chain(&self.by)
+    }
+
+    pub fn to_tokens(&self) -> TokenStream {
+        let time = &self.time;
+        let by = match &self.by {
+            Some(by) => quote!(Some(#by)),
+            None => quote!(None),
+        };
+        let every = duration_tokens(self.every);
+        let max_gap = duration_tokens(self.max_gap);
+        quote! {
+            ::polars_schema_validate::TimeSeries {
+                time: #time,
+                by: #by,
+                every: #every,
+                max_gap: #max_gap,
+            }
+        }
+    }
+}
+
+/// Parses `timeseries(time = "ts", every = "1m", by = "sensor_id", max_gap = "5m")`.
+pub fn parse_timeseries(meta: &ParseNestedMeta) -> syn::Result<TimeSeriesAttrs> {
+    let mut time = None;
+    let mut by = None;
+    let mut every = None;
+    let mut max_gap = None;
+
+    meta.parse_nested_meta(|option| {
+        let lit: LitStr = option.value()?.parse()?;
+        if option.path.is_ident("time") || option.path.is_ident("by") {
+            if lit.value().is_empty() {
+                return Err(syn::Error::new_spanned(lit, "column name must not be empty"));
+            }
+            if option.path.is_ident("time") {
+                time = Some(lit);
+            } else {
+                by = Some(lit);
+            }
+        } else if option.path.is_ident("every") {
+            every = Some(parse_duration(&lit)?);
+        } else if option.path.is_ident("max_gap") {
+            max_gap = Some(parse_duration(&lit)?);
+        } else {
+            return Err(option.error("expected one of `time`, `by`, `every`, `max_gap`"));
+        }
+        Ok(())
+    })?;
+
+    let time = time.ok_or_else(|| meta.error("timeseries requires `time = \"column\"`"))?;
+    if let (Some(every), Some(max_gap)) = (every, max_gap) {
+        if max_gap < every {
+            return Err(meta.error("max_gap must not be shorter than every"));
+        }
+    }
+    Ok(TimeSeriesAttrs { time, by, every, max_gap })
+}
+
+/// Parses a fixed-length duration in Polars' syntax, e.g. `"1h30m"`, into nanoseconds.
+fn parse_duration(lit: &LitStr) -> syn::Result<u64> {
+    let error = |message: &str| syn::Error::new_spanned(lit, message);
+    let invalid = || error("invalid duration, expected e.g. \"30s\", \"5m\" or \"1h30m\"");
+
+    let value = lit.value();
+    let mut rest = value.as_str();
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut nanos: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let unit_end = rest[digits..].find(|c: char| c.is_ascii_digit()).map_or(rest.len(), |end| digits + end);
+        let number: u64 = rest[..digits].parse().map_err(|_| invalid())?;
+        let unit_nanos: u64 = match &rest[digits..unit_end] {
+            "ns" => 1,
+            "us" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            "d" => 86_400_000_000_000,
+            "w" => 604_800_000_000_000,
+            "mo" | "q" | "y" => {
+                return Err(error("calendar durations are not supported, use a fixed duration like \"30d\""));
+            }
+            _ => return Err(invalid()),
+        };
+        nanos = number
+            .checked_mul(unit_nanos)
+            .and_then(|value| nanos.checked_add(value))
+            .ok_or_else(|| error("duration is too long"))?;
+        rest = &rest[unit_end..];
+    }
+
+    if nanos == 0 {
+        return Err(error("duration must be positive"));
+    }
+    Ok(nanos)
+}
+
+fn duration_tokens(nanos: Option<u64>) -> TokenStream {
+    match nanos {
+        Some(nanos) => quote!(Some(::std::time::Duration::from_nanos(#nanos))),
+        None => quote!(None),
+    }
+}
@@ -0,0 +1,44 @@
+use polars::prelude::*;
+
+/// A named row-level rule spanning several columns, such as `start <= end`.
+///
+/// Generated by struct attributes of `#[derive(PolarsSchema)]`, either from an
+/// expression or from a function returning one:
+///
+/// ```rust
+/// use polars::prelude::*;
+/// use polars_schema_validate::PolarsSchema;
+///
+/// fn discount_below_price() -> Expr {
+///     col("discount").lt(col("price"))
+/// }
+///
+/// #[derive(PolarsSchema)]
+/// #[polars(check = "col(\"start\").lt_eq(col(\"end\"))", name = "start_before_end")]
+/// #[polars(check = discount_below_price)]
+/// struct Promotion {
+///     start: i32,
+///     end: i32,
+///     price: f64,
+///     discount: f64,
+/// }
+///
+/// assert_eq!(Promotion::checks()[0].name, "start_before_end");
+/// assert_eq!(Promotion::checks()[1].name, "discount_below_price");
+/// ```
+///
+/// Rows where the expression is `false` fail the check, null counts as passing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// Name reported in errors
+    pub name: &'static str,
+    /// Boolean expression that is `true` for valid rows
+    pub expr: Expr,
+}
+
+impl Check {
+    /// Creates a check with the given name and expression.
+    pub fn new(name: &'static str, expr: Expr) -> Self {
+        Self { name, expr }
+    }
+}
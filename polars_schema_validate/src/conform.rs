@@ -0,0 +1,125 @@
+use polars::prelude::*;
+
+use crate::error::{Result, ValidationError};
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+
+/// How [`PolarsSchema::conform_with`](crate::PolarsSchema::conform_with) casts columns
+/// whose dtype differs from the schema.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CastMode {
+    /// Casts that would lose data fail with [`ValidationError::LossyCast`].
+    #[default]
+    Strict,
+    /// Values that cannot be represented in the target dtype become null.
+    Lenient,
+}
+
+/// Builds a DataFrame with exactly the schema's columns, in declaration order.
+///
+/// Missing columns of nullable fields are filled with nulls, other missing columns and
+/// failed casts are reported as errors.
+pub(crate) fn conform_frame(fields: &[FieldSpec], df: &DataFrame, mode: CastMode) -> Result<DataFrame> {
+    let mut report = ValidationReport::new();
+    let mut columns = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        match df.column(field.name) {
+            Err(_) if field.nullable => {
+                columns.push(Column::full_null(field.name.into(), df.height(), &field.dtype));
+            }
+            Err(_) => report.push(ValidationError::MissingColumn {
+                column_name: field.name.to_string(),
+            }),
+            Ok(column) if column.dtype() == &field.dtype => columns.push(column.clone()),
+            Ok(column) => match cast_series(field, column.as_materialized_series(), mode) {
+                Ok(series) => columns.push(series.into()),
+                Err(error) => report.push(error),
+            },
+        }
+    }
+
+    report.into_first_error()?;
+    Ok(DataFrame::new(columns)?)
+}
+
+/// Builds a LazyFrame plan that selects, casts and fills the schema's columns.
+///
+/// Lossy casts cannot be detected without the data: in strict mode each cast is checked
+/// like [`conform_frame`] does when the plan runs, and a lossy one makes it fail when
+/// collected with a Polars error carrying the [`ValidationError::LossyCast`] message.
+pub(crate) fn conform_plan(fields: &[FieldSpec], mut lf: LazyFrame, mode: CastMode) -> Result<LazyFrame> {
+    let lf_schema = lf.collect_schema()?;
+    let mut report = ValidationReport::new();
+    let mut exprs = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        match lf_schema.get(field.name) {
+            None if field.nullable => {
+                exprs.push(lit(NULL).cast(field.dtype.clone()).alias(field.name));
+            }
+            None => report.push(ValidationError::MissingColumn {
+                column_name: field.name.to_string(),
+            }),
+            Some(dtype) if dtype == &field.dtype => exprs.push(col(field.name)),
+            Some(_) => exprs.push(match mode {
+                CastMode::Strict => {
+                    let target = FieldSpec::new(field.name, field.dtype.clone());
+                    col(field.name).map(
+                        move |column| match cast_series(&target, column.as_materialized_series(), mode) {
+                            Ok(series) => Ok(Some(series.into())),
+                            Err(error) => Err(polars_err!(ComputeError: "{}", error)),
+                        },
+                        GetOutput::from_type(field.dtype.clone()),
+                    )
+                }
+                CastMode::Lenient => col(field.name).cast(field.dtype.clone()),
+            }),
+        }
+    }
+
+    report.into_first_error()?;
+    Ok(lf.select(exprs))
+}
+
+fn cast_series(field: &FieldSpec, series: &Series, mode: CastMode) -> Result<Series> {
+    let casted = series
+        .cast(&field.dtype)
+        .map_err(|_| ValidationError::TypeMismatch {
+            column_name: field.name.to_string(),
+            expected_type: format!("{:?}", field.dtype),
+            actual_type: format!("{:?}", series.dtype()),
+        })?;
+
+    if mode == CastMode::Strict {
+        let lossy_count = count_lossy(series, &casted);
+        if lossy_count > 0 {
+            return Err(ValidationError::LossyCast {
+                column_name: field.name.to_string(),
+                from_type: format!("{:?}", series.dtype()),
+                to_type: format!("{:?}", field.dtype),
+                lossy_count,
+            });
+        }
+    }
+
+    Ok(casted)
+}
+
+/// Counts values that did not survive a cast: values that became null and, for
+/// numeric casts, values that changed (e.g. truncated floats or overflowing integers).
+fn count_lossy(original: &Series, casted: &Series) -> usize {
+    let original_valid = original.is_not_null();
+    let mut lossy = &original_valid & &casted.is_null();
+
+    if original.dtype().is_primitive_numeric() && casted.dtype().is_primitive_numeric() {
+        let changed = casted
+            .cast(original.dtype())
+            .and_then(|round_trip| round_trip.not_equal_missing(original));
+        if let Ok(changed) = changed {
+            lossy = &lossy | &(&original_valid & &changed);
+        }
+    }
+
+    lossy.sum().unwrap_or(0) as usize
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use polars::prelude::*;
+
+/// A literal value used in a [`Constraint`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintValue {
+    Int(i64),
+    Float(f64),
+    Str(&'static str),
+}
+
+impl ConstraintValue {
+    fn to_expr(&self) -> Expr {
+        match self {
+            ConstraintValue::Int(value) => lit(*value),
+            ConstraintValue::Float(value) => lit(*value),
+            ConstraintValue::Str(value) => lit(*value),
+        }
+    }
+}
+
+impl fmt::Display for ConstraintValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintValue::Int(value) => write!(f, "{}", value),
+            ConstraintValue::Float(value) => write!(f, "{:?}", value),
+            ConstraintValue::Str(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+/// How many rows may fail a [`Constraint`] before validation fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// At least this fraction of the rows must pass, e.g. `0.995`
+    Mostly(f64),
+    /// At most this many rows may fail
+    MaxFailures(usize),
+}
+
+impl Tolerance {
+    /// Returns `true` if `failing_count` failures out of `row_count` rows are within budget.
+    pub fn allows(&self, failing_count: usize, row_count: usize) -> bool {
+        match self {
+            Tolerance::Mostly(fraction) => {
+                row_count == 0 || (row_count - failing_count) as f64 / row_count as f64 >= *fraction
+            }
+            Tolerance::MaxFailures(max_failures) => failing_count <= *max_failures,
+        }
+    }
+}
+
+/// Tolerances of the other row-level checks of a field, set in the same attribute as
+/// the check, e.g. `#[polars(not_null, mostly = 0.99)]` or `#[polars(strictly_increasing, max_failures = 3)]`.
+///
+/// Constraints carry their tolerance in [`Constraint::Tolerant`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tolerances {
+    /// Tolerance of null values; also checks an `Option<T>` field for nulls
+    pub not_null: Option<Tolerance>,
+    /// Tolerance of NaN values in a float column
+    pub not_nan: Option<Tolerance>,
+    /// Tolerance of infinite values in a float column
+    pub finite: Option<Tolerance>,
+    /// Tolerance of values that are not categories of an enum column
+    pub category: Option<Tolerance>,
+    /// Tolerance of rows out of order in a sorted column
+    pub sorted: Option<Tolerance>,
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tolerance::Mostly(fraction) => write!(f, "mostly = {}", fraction),
+            Tolerance::MaxFailures(max_failures) => write!(f, "max_failures = {}", max_failures),
+        }
+    }
+}
+
+/// A row-level check on the values of a column.
+///
+/// Generated by field attributes of `#[derive(PolarsSchema)]`:
+///
+/// | Attribute | Constraint |
+/// |-----------|------------|
+/// | `#[polars(min = 0)]` | `Min(Int(0))` |
+/// | `#[polars(max = 1.5)]` | `Max(Float(1.5))` |
+/// | `#[polars(len(min = 1, max = 64))]` | `Length { min: Some(1), max: Some(64) }` |
+/// | `#[polars(regex = "^[A-Z]{2}$")]` | `Regex("^[A-Z]{2}$")` |
+/// | `#[polars(one_of("a", "b"))]` | `OneOf(vec![Str("a"), Str("b")])` |
+/// | `#[polars(min = 0, mostly = 0.99)]` | `Tolerant(Box::new(Min(Int(0))), Mostly(0.99))` |
+///
+/// Null values never violate a constraint, nullability is checked separately.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// Values must be greater than or equal to the bound
+    Min(ConstraintValue),
+    /// Values must be less than or equal to the bound
+    Max(ConstraintValue),
+    /// String lengths (in characters) or list lengths must be within the bounds
+    Length { min: Option<usize>, max: Option<usize> },
+    /// String values must match the regular expression
+    Regex(&'static str),
+    /// Values must be one of the listed values
+    OneOf(Vec<ConstraintValue>),
+    /// The inner constraint may fail for some rows, within the tolerance
+    Tolerant(Box<Constraint>, Tolerance),
+}
+
+impl Constraint {
+    /// Returns a boolean expression that is `true` for rows satisfying the constraint.
+    ///
+    /// `dtype` is the dtype of the column, used to pick between string and list lengths.
+    pub fn expr(&self, column: &str, dtype: &DataType) -> Expr {
+        self.expr_on(col(column), dtype)
+    }
+
+    /// Like [`expr`](Self::expr), but checks the values of an expression, such as a
+    /// field of a struct column.
+    pub fn expr_on(&self, value: Expr, dtype: &DataType) -> Expr {
+        match self {
+            Constraint::Min(min) => value.gt_eq(min.to_expr()),
+            Constraint::Max(max) => value.lt_eq(max.to_expr()),
+            Constraint::Length { min, max } => {
+                let len = match dtype {
+                    DataType::List(_) => value.list().len(),
+                    DataType::Array(_, width) => lit(*width as u32),
+                    _ => value.str().len_chars(),
+                };
+                let mut valid = lit(true);
+                if let Some(min) = min {
+                    valid = valid.and(len.clone().gt_eq(lit(*min as u32)));
+                }
+                if let Some(max) = max {
+                    valid = valid.and(len.lt_eq(lit(*max as u32)));
+                }
+                valid
+            }
+            Constraint::Regex(pattern) => value.str().contains(lit(*pattern), true),
+            Constraint::OneOf(values) => values
+                .iter()
+                .map(|one| value.clone().eq(one.to_expr()))
+                .reduce(|acc, expr| acc.or(expr))
+                .unwrap_or(lit(false)),
+            Constraint::Tolerant(constraint, _) => constraint.expr_on(value, dtype),
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::Min(min) => write!(f, "min = {}", min),
+            Constraint::Max(max) => write!(f, "max = {}", max),
+            Constraint::Length { min, max } => {
+                let bounds: Vec<_> = [("min", min), ("max", max)]
+                    .into_iter()
+                    .filter_map(|(name, bound)| bound.map(|bound| format!("{} = {}", name, bound)))
+                    .collect();
+                write!(f, "len({})", bounds.join(", "))
+            }
+            Constraint::Regex(pattern) => write!(f, "regex = {:?}", pattern),
+            Constraint::OneOf(values) => {
+                let values: Vec<_> = values.iter().map(ToString::to_string).collect();
+                write!(f, "one_of({})", values.join(", "))
+            }
+            Constraint::Tolerant(constraint, tolerance) => write!(f, "{} ({})", constraint, tolerance),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use polars::prelude::*;
+
+use crate::field::FieldSpec;
+
+/// Maps a Rust type to the Polars [`DataType`] of the column that stores it.
+///
+/// `#[derive(PolarsSchema)]` resolves every field type through this trait, so any type
+/// implementing it can be used as a field, including your own domain types:
+///
+/// ```rust
+/// use polars::prelude::*;
+/// use polars_schema_validate::{PolarsDtype, PolarsSchema};
+///
+/// struct CustomerId(i64);
+///
+/// impl PolarsDtype for CustomerId {
+///     fn dtype() -> DataType {
+///         DataType::Int64
+///     }
+/// }
+///
+/// #[derive(PolarsSchema)]
+/// struct Order {
+///     customer_id: CustomerId,
+///     note: Option<String>,
+/// }
+///
+/// assert_eq!(Order::schema()[0].1, DataType::Int64);
+/// ```
+///
+/// Structs deriving `PolarsSchema` implement `PolarsDtype` as a `DataType::Struct` of
+/// their fields, so they can be nested in other schemas.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a PolarsSchema field type",
+    label = "no Polars dtype is known for `{Self}`",
+    note = "supported types are i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool, String, &str, \
+            chrono's NaiveDate, NaiveDateTime, NaiveTime and DateTime<Utc>, structs deriving PolarsSchema, \
+            enums deriving PolarsEnum, \
+            and Option<T>, Vec<T> and [T; N] of these",
+    note = "implement `PolarsDtype` for `{Self}` to use it in a schema"
+)]
+pub trait PolarsDtype {
+    /// Whether values of this type may be null (`true` for `Option<T>`).
+    const NULLABLE: bool = false;
+
+    /// Returns the Polars data type of the column.
+    fn dtype() -> DataType;
+
+    /// Returns the fields of a struct column, used to validate nested data.
+    ///
+    /// Types deriving `PolarsSchema` return their own [`fields`](crate::PolarsSchema::fields).
+    fn nested_fields() -> Vec<FieldSpec> {
+        Vec::new()
+    }
+
+    /// Returns the allowed values of a categorical column, used to validate
+    /// `String` and `Categorical` columns.
+    ///
+    /// Types deriving `PolarsEnum` return their variant names.
+    fn categories() -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// Returns the items of a list or array column, used to validate their
+    /// nullability, categories and nested fields.
+    ///
+    /// `Vec<T>` and `[T; N]` return a spec built from `T`.
+    fn items() -> Option<FieldSpec> {
+        None
+    }
+}
+
+/// Builds the spec of the items of a list or array of `T`.
+fn item_spec<T: PolarsDtype>() -> FieldSpec {
+    FieldSpec {
+        nullable: T::NULLABLE,
+        fields: T::nested_fields(),
+        items: T::items().map(Box::new),
+        categories: T::categories(),
+        ..FieldSpec::new("", T::dtype())
+    }
+}
+
+/// A Rust unit enum stored as a Polars `Enum` or `Categorical` column.
+///
+/// This trait is implemented with `#[derive(PolarsEnum)]`, which also implements
+/// [`PolarsDtype`] so the enum can be used as a field of a `PolarsSchema` struct.
+/// The column dtype is `Enum` with the variant names as categories, or `Categorical`
+/// with `#[polars(categorical)]`. Variants can be renamed with `#[polars(rename = "...")]`
+/// and `#[polars(rename_all = "...")]`.
+///
+/// When a field's column is a `String` or `Categorical` column, e.g. through
+/// `#[polars(dtype = "String")]`, validation reports values that are not variant names.
+///
+/// ```rust
+/// use polars_schema_validate::{PolarsEnum, PolarsSchema};
+///
+/// #[derive(PolarsEnum)]
+/// #[polars(rename_all = "lowercase")]
+/// enum Status {
+///     Pending,
+///     Shipped,
+///     Cancelled,
+/// }
+///
+/// #[derive(PolarsSchema)]
+/// struct Order {
+///     id: i64,
+///     status: Status,
+/// }
+///
+/// assert_eq!(Status::VARIANTS, &["pending", "shipped", "cancelled"]);
+/// assert_eq!(Status::Shipped.as_str(), "shipped");
+/// ```
+pub trait PolarsEnum {
+    /// Category names of the variants, in declaration order.
+    const VARIANTS: &'static [&'static str];
+
+    /// Returns the category name of this variant.
+    fn as_str(&self) -> &'static str;
+}
+
+/// Builds a Polars `Enum` dtype with the given categories.
+pub fn enum_dtype(categories: &[&str]) -> DataType {
+    let ca = StringChunked::from_slice("".into(), categories);
+    let array = ca
+        .downcast_iter()
+        .next()
+        .expect("from_slice creates a single chunk")
+        .clone();
+    create_enum_dtype(array)
+}
+
+macro_rules! impl_polars_dtype {
+    ($($ty:ty => $dtype:expr),* $(,)?) => {
+        $(
+            impl PolarsDtype for $ty {
+                fn dtype() -> DataType {
+                    $dtype
+                }
+            }
+        )*
+    };
+}
+
+impl_polars_dtype! {
+    i8 => DataType::Int8,
+    i16 => DataType::Int16,
+    i32 => DataType::Int32,
+    i64 => DataType::Int64,
+    u8 => DataType::UInt8,
+    u16 => DataType::UInt16,
+    u32 => DataType::UInt32,
+    u64 => DataType::UInt64,
+    f32 => DataType::Float32,
+    f64 => DataType::Float64,
+    bool => DataType::Boolean,
+    String => DataType::String,
+    &str => DataType::String,
+}
+
+#[cfg(feature = "chrono")]
+impl_polars_dtype! {
+    chrono::NaiveDate => DataType::Date,
+    chrono::NaiveDateTime => DataType::Datetime(TimeUnit::Microseconds, None),
+    chrono::NaiveTime => DataType::Time,
+    chrono::DateTime<chrono::Utc> => DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+}
+
+impl<T: PolarsDtype> PolarsDtype for Option<T> {
+    const NULLABLE: bool = true;
+
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    fn nested_fields() -> Vec<FieldSpec> {
+        T::nested_fields()
+    }
+
+    fn categories() -> Option<Vec<&'static str>> {
+        T::categories()
+    }
+
+    fn items() -> Option<FieldSpec> {
+        T::items()
+    }
+}
+
+impl<T: PolarsDtype> PolarsDtype for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(T::dtype()))
+    }
+
+    fn items() -> Option<FieldSpec> {
+        Some(item_spec::<T>())
+    }
+}
+
+impl<T: PolarsDtype, const N: usize> PolarsDtype for [T; N] {
+    fn dtype() -> DataType {
+        DataType::Array(Box::new(T::dtype()), N)
+    }
+
+    fn items() -> Option<FieldSpec> {
+        Some(item_spec::<T>())
+    }
+}
@@ -1,5 +1,9 @@
 use std::fmt;
 
+use polars::prelude::PolarsError;
+
+use crate::rows::MAX_SAMPLE_ROWS;
+
 /// Error types that can occur during schema validation
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValidationError {
@@ -22,6 +26,114 @@ pub enum ValidationError {
     UnexpectedColumn {
         column_name: String,
     },
+    /// A column of a non-`Option` field contains null values
+    NullValues {
+        column_name: String,
+        null_count: usize,
+        /// Indices of the first null rows, capped at a small sample
+        rows: Vec<usize>,
+    },
+    /// A column of a `PolarsEnum` field contains values that are not variants
+    InvalidCategory {
+        column_name: String,
+        invalid_count: usize,
+        /// Distinct invalid values, capped at a small sample
+        values: Vec<String>,
+        /// Indices of the first rows with an invalid value, capped at a small sample
+        rows: Vec<usize>,
+    },
+    /// Values of a column violate a value constraint
+    ConstraintViolation {
+        column_name: String,
+        constraint: String,
+        failing_count: usize,
+        /// `(row_index, value)` pairs of the first failing rows, capped at a small sample
+        sample: Vec<(usize, String)>,
+    },
+    /// Rows of a column fail another row-level check, such as `not_nan` and `finite`
+    /// for float fields or `sorted` for ordered fields
+    InvalidRows {
+        column_name: String,
+        check: String,
+        failing_count: usize,
+        /// `(row_index, value)` pairs of the first failing rows, capped at a small sample
+        sample: Vec<(usize, String)>,
+    },
+    /// Rows of a column fail a constraint or check with a tolerance, reported as an error when the
+    /// failures exceed the tolerance and as [`ValidationReport::tolerated`] otherwise
+    ///
+    /// [`ValidationReport::tolerated`]: crate::ValidationReport::tolerated
+    FailureRate {
+        column_name: String,
+        check: String,
+        failing_count: usize,
+        row_count: usize,
+        /// The tolerance, e.g. `mostly = 0.995`
+        tolerance: String,
+        /// `(row_index, value)` pairs of the first failing rows, capped at a small sample
+        sample: Vec<(usize, String)>,
+    },
+    /// A column statistic, such as the null fraction, is outside its expected bounds
+    StatisticOutOfRange {
+        column_name: String,
+        statistic: String,
+        observed: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// Rows fail a struct-level check spanning several columns
+    FailedCheck {
+        check: String,
+        failing_count: usize,
+        /// Indices of the first failing rows, capped at a small sample
+        rows: Vec<usize>,
+    },
+    /// Values of a unique column or primary key occur more than once
+    DuplicateKeys {
+        columns: Vec<String>,
+        /// Number of distinct key values that occur more than once
+        duplicate_count: usize,
+        /// The first duplicated key values, capped at a small sample
+        sample: Vec<String>,
+    },
+    /// Values of a foreign key column are not found in the referenced column
+    OrphanedKeys {
+        column_name: String,
+        /// Referenced column as `Table.column`
+        referenced: String,
+        /// Number of rows with an orphaned key
+        orphan_count: usize,
+        /// The first distinct orphaned key values, capped at a small sample
+        sample: Vec<String>,
+    },
+    /// Consecutive timestamps of a time series are further apart than allowed
+    /// (`#[polars(timeseries(...))]`)
+    TimeGaps {
+        column_name: String,
+        /// The longest allowed interval, e.g. `5m`
+        max_gap: String,
+        /// Number of intervals longer than `max_gap`
+        gap_count: usize,
+        /// The first gaps as `entity: start -> end`, capped at a small sample
+        sample: Vec<String>,
+    },
+    /// Casting a column to the schema's dtype would lose data
+    LossyCast {
+        column_name: String,
+        from_type: String,
+        to_type: String,
+        lossy_count: usize,
+    },
+    /// Polars failed while inspecting the data, e.g. resolving a LazyFrame's schema
+    Polars {
+        message: String,
+    },
+    /// DataFrame has fewer or more rows than expected (`#[polars(rows(...))]`)
+    RowCount {
+        row_count: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+    },
 }
 
 impl fmt::Display for ValidationError {
@@ -39,19 +151,119 @@ impl fmt::Display for ValidationError {
             ValidationError::UnexpectedColumn { column_name } => {
                 write!(f, "Unexpected column '{}' found in DataFrame", column_name)
             }
+            ValidationError::NullValues { column_name, null_count, rows } => {
+                write!(f, "Column '{}' contains {} null value(s) but is not nullable", column_name, null_count)?;
+                write_sample(f, "rows ", rows, *null_count)
+            }
+            ValidationError::InvalidCategory { column_name, invalid_count, values, rows } => {
+                let values: Vec<_> = values.iter().map(|v| format!("'{}'", v)).collect();
+                write!(f, "Column '{}' contains {} value(s) that are not valid categories: {}", column_name, invalid_count, values.join(", "))?;
+                write_sample(f, "rows ", rows, *invalid_count)
+            }
+            ValidationError::ConstraintViolation { column_name, constraint, failing_count, sample } => {
+                write!(f, "Column '{}' violates constraint {} in {} row(s)", column_name, constraint, failing_count)?;
+                write_sample(f, "", &row_values(sample), *failing_count)
+            }
+            ValidationError::InvalidRows { column_name, check, failing_count, sample } => {
+                write!(f, "Column '{}' failed check {} in {} row(s)", column_name, check, failing_count)?;
+                write_sample(f, "", &row_values(sample), *failing_count)
+            }
+            ValidationError::FailureRate { column_name, check, failing_count, row_count, tolerance, sample } => {
+                let rate = if *row_count == 0 { 0.0 } else { *failing_count as f64 / *row_count as f64 };
+                write!(
+                    f,
+                    "Column '{}' failed check {} in {} of {} row(s) ({:.2}%, {})",
+                    column_name,
+                    check,
+                    failing_count,
+                    row_count,
+                    rate * 100.0,
+                    tolerance
+                )?;
+                write_sample(f, "", &row_values(sample), *failing_count)
+            }
+            ValidationError::StatisticOutOfRange { column_name, statistic, observed, min, max } => {
+                write!(f, "Column '{}' has {} = {} but expected ", column_name, statistic, observed)?;
+                match (min, max) {
+                    (Some(min), Some(max)) => write!(f, "between {} and {}", min, max),
+                    (Some(min), None) => write!(f, "at least {}", min),
+                    (None, Some(max)) => write!(f, "at most {}", max),
+                    (None, None) => write!(f, "any value"),
+                }
+            }
+            ValidationError::FailedCheck { check, failing_count, rows } => {
+                write!(f, "Check '{}' failed in {} row(s)", check, failing_count)?;
+                write_sample(f, "rows ", rows, *failing_count)
+            }
+            ValidationError::DuplicateKeys { columns, duplicate_count, sample } => {
+                write!(f, "Key ({}) has {} duplicate value(s)", columns.join(", "), duplicate_count)?;
+                write_sample(f, "", sample, *duplicate_count)
+            }
+            ValidationError::OrphanedKeys { column_name, referenced, orphan_count, sample } => {
+                write!(f, "Column '{}' has {} row(s) with keys not found in {}", column_name, orphan_count, referenced)?;
+                // The sample holds distinct keys, so it is only truncated once it is full
+                let total = if sample.len() >= MAX_SAMPLE_ROWS { *orphan_count } else { sample.len() };
+                write_sample(f, "", sample, total)
+            }
+            ValidationError::TimeGaps { column_name, max_gap, gap_count, sample } => {
+                write!(f, "Column '{}' has {} gap(s) longer than {}", column_name, gap_count, max_gap)?;
+                write_sample(f, "", sample, *gap_count)
+            }
+            ValidationError::LossyCast { column_name, from_type, to_type, lossy_count } => {
+                write!(f, "Casting column '{}' from {} to {} would lose {} value(s)", column_name, from_type, to_type, lossy_count)
+            }
+            ValidationError::Polars { message } => {
+                write!(f, "Polars error: {}", message)
+            }
+            ValidationError::RowCount { row_count, min, max } => {
+                write!(f, "DataFrame has {} row(s) but expected ", row_count)?;
+                match (min, max) {
+                    (Some(min), Some(max)) => write!(f, "between {} and {}", min, max),
+                    (Some(min), None) => write!(f, "at least {}", min),
+                    (None, Some(max)) => write!(f, "at most {}", max),
+                    (None, None) => write!(f, "any number"),
+                }
+            }
         }
     }
 }
 
 impl std::error::Error for ValidationError {}
 
+impl From<PolarsError> for ValidationError {
+    fn from(err: PolarsError) -> Self {
+        ValidationError::Polars {
+            message: err.to_string(),
+        }
+    }
+}
+
 // ValidationError is automatically Send + Sync because:
-// - String is Send + Sync  
+// - String, Vec<String> and Vec<(usize, String)> are Send + Sync  
 // - usize is Send + Sync (Copy types are automatically thread-safe)
 // - No raw pointers, references, or non-thread-safe types
 
 pub type Result<T> = std::result::Result<T, ValidationError>;
 
+/// Writes a sample of the failing items as ` [<label>a, b, ...]`, ending with `...` if
+/// the sample holds fewer than `total` items, or nothing for an empty sample.
+fn write_sample<T: fmt::Display>(f: &mut fmt::Formatter<'_>, label: &str, items: &[T], total: usize) -> fmt::Result {
+    if items.is_empty() {
+        return Ok(());
+    }
+    let items: Vec<_> = items.iter().map(ToString::to_string).collect();
+    write!(f, " [{}{}", label, items.join(", "))?;
+    if total > items.len() {
+        write!(f, ", ...")?;
+    }
+    write!(f, "]")
+}
+
+/// Formats `(row_index, value)` pairs as `row i: v`.
+fn row_values(sample: &[(usize, String)]) -> Vec<String> {
+    sample.iter().map(|(row, value)| format!("row {}: {}", row, value)).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +296,4 @@ mod tests {
         let result = handle.join().unwrap();
         assert_eq!(result, "Column 'test_column' not found in DataFrame");
     }
-}
\ No newline at end of file
+}
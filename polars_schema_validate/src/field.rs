@@ -0,0 +1,86 @@
+use polars::prelude::*;
+
+use crate::constraint::{Constraint, Tolerances};
+use crate::foreign_key::ForeignKey;
+use crate::sorted::Sortedness;
+use crate::statistic::Expectation;
+use crate::validator::Validator;
+
+/// Description of a single column expected by a [`PolarsSchema`](crate::PolarsSchema).
+///
+/// The derive macro generates one `FieldSpec` per struct field. Manual implementations
+/// can start from [`FieldSpec::new`] and override individual settings with struct
+/// update syntax:
+///
+/// ```rust
+/// use polars::prelude::*;
+/// use polars_schema_validate::FieldSpec;
+///
+/// let id = FieldSpec { nullable: false, ..FieldSpec::new("id", DataType::Int64) };
+/// assert!(!id.nullable);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSpec {
+    /// Column name in the DataFrame
+    pub name: &'static str,
+    /// Expected Polars data type of the column
+    pub dtype: DataType,
+    /// Whether the column may contain null values (`Option<T>` fields)
+    pub nullable: bool,
+    /// Fields of a nested struct column, empty for all other columns
+    pub fields: Vec<FieldSpec>,
+    /// Items of a list or array column (`Vec<T>` and `[T; N]` fields), `None` for all
+    /// other columns
+    pub items: Option<Box<FieldSpec>>,
+    /// Whether a float column may contain NaN values (`#[polars(allow_nan)]`)
+    pub allow_nan: bool,
+    /// Whether a float column may contain infinite values (`#[polars(allow_inf)]`)
+    pub allow_inf: bool,
+    /// Allowed values of a categorical column (`PolarsEnum` fields)
+    pub categories: Option<Vec<&'static str>>,
+    /// Row-level value constraints (`#[polars(min = ..., regex = ...)]` attributes)
+    pub constraints: Vec<Constraint>,
+    /// Tolerances of the nullability, NaN, infinite, category and ordering checks
+    /// (`#[polars(not_null, mostly = ...)]`)
+    pub tolerances: Tolerances,
+    /// Whether the column's values must be unique (`#[polars(unique)]`)
+    pub unique: bool,
+    /// Order the column's values must follow (`#[polars(sorted = "asc")]`)
+    pub sorted: Option<Sortedness>,
+    /// Column of another schema this column references (`#[polars(foreign_key = "...")]`)
+    pub foreign_key: Option<ForeignKey>,
+    /// Bounds on column statistics (`#[polars(max_null_fraction = ..., distinct(...))]`)
+    pub expectations: Vec<Expectation>,
+    /// Custom validation functions run on the column (`#[polars(validate_with = "...")]`)
+    pub validators: Vec<Validator<Series>>,
+}
+
+impl FieldSpec {
+    /// Creates a nullable field with the given column name and data type.
+    ///
+    /// NaN and infinite values are rejected in float columns unless allowed explicitly.
+    pub fn new(name: &'static str, dtype: DataType) -> Self {
+        Self {
+            name,
+            dtype,
+            nullable: true,
+            fields: Vec::new(),
+            items: None,
+            allow_nan: false,
+            allow_inf: false,
+            categories: None,
+            constraints: Vec::new(),
+            tolerances: Tolerances::default(),
+            unique: false,
+            sorted: None,
+            foreign_key: None,
+            expectations: Vec::new(),
+            validators: Vec::new(),
+        }
+    }
+
+    /// Converts the spec into a Polars [`Field`], e.g. to build a `DataType::Struct`.
+    pub fn to_field(&self) -> Field {
+        Field::new(self.name.into(), self.dtype.clone())
+    }
+}
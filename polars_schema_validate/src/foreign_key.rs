@@ -0,0 +1,160 @@
+use std::any::TypeId;
+
+use polars::prelude::*;
+
+use crate::error::ValidationError;
+use crate::report::ValidationReport;
+use crate::rows::{format_value, MAX_SAMPLE_ROWS};
+use crate::PolarsSchema;
+
+/// Name of the temporary key column used to join the two DataFrames.
+const KEY: &str = "__polars_schema_key";
+/// Name of the temporary column holding the number of rows per orphaned key.
+const KEY_COUNT: &str = "__polars_schema_key_count";
+
+/// A reference from a column to a column of another schema.
+///
+/// Generated by `#[polars(foreign_key = "Customer::id")]`, where `id` is the column name
+/// in the referenced schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey {
+    /// Referenced schema as written in the attribute, e.g. `Customer`
+    pub table: &'static str,
+    /// [`TypeId`] of the referenced schema, used to match it
+    pub type_id: TypeId,
+    /// Referenced column name
+    pub column: &'static str,
+}
+
+impl ForeignKey {
+    /// Referenced column qualified with its schema, e.g. `Customer.id`
+    fn referenced(&self) -> String {
+        format!("{}.{}", self.table, self.column)
+    }
+}
+
+/// Checks that every key of `A` referencing `B` exists in `B`.
+///
+/// For every field of `A` with a `#[polars(foreign_key = ...)]` attribute pointing at
+/// `B`, rows of `df` whose key is not found in `referenced` are reported as
+/// [`ValidationError::OrphanedKeys`]. Null keys are not checked, nullability is
+/// validated separately.
+///
+/// A referenced column that is not a field of `B`, or is missing from `referenced`, is
+/// reported as [`ValidationError::MissingColumn`] named `Table.column`.
+///
+/// # Example
+///
+/// ```rust
+/// use polars::prelude::*;
+/// use polars_schema_validate::{check_foreign_keys, PolarsSchema};
+///
+/// #[derive(PolarsSchema)]
+/// struct Customer {
+///     id: i64,
+/// }
+///
+/// #[derive(PolarsSchema)]
+/// struct Order {
+///     #[polars(foreign_key = "Customer::id")]
+///     customer_id: i64,
+/// }
+///
+/// let customers = df!["id" => [1i64, 2]].unwrap();
+/// let orders = df!["customer_id" => [1i64, 2, 3]].unwrap();
+///
+/// let report = check_foreign_keys::<Order, Customer>(&orders, &customers);
+/// assert_eq!(report.len(), 1);
+/// ```
+pub fn check_foreign_keys<A, B>(df: &DataFrame, referenced: &DataFrame) -> ValidationReport
+where
+    A: PolarsSchema,
+    B: PolarsSchema + 'static,
+{
+    let type_id = TypeId::of::<B>();
+    let referenced_fields = B::fields();
+    let mut report = ValidationReport::new();
+
+    for field in A::fields() {
+        let Some(foreign_key) = &field.foreign_key else {
+            continue;
+        };
+        if foreign_key.type_id != type_id {
+            continue;
+        }
+        if !referenced_fields.iter().any(|referenced| referenced.name == foreign_key.column) {
+            report.push(ValidationError::MissingColumn { column_name: foreign_key.referenced() });
+            continue;
+        }
+        if let Err(err) = check_foreign_key(field.name, foreign_key, df, referenced, &mut report) {
+            report.push(err.into());
+        }
+    }
+
+    report
+}
+
+fn check_foreign_key(
+    column_name: &str,
+    foreign_key: &ForeignKey,
+    df: &DataFrame,
+    referenced: &DataFrame,
+    report: &mut ValidationReport,
+) -> PolarsResult<()> {
+    let (keys, referenced_keys) = match (df.column(column_name), referenced.column(foreign_key.column)) {
+        (Ok(keys), Ok(referenced_keys)) => (keys, referenced_keys),
+        (keys, referenced_keys) => {
+            if keys.is_err() {
+                report.push(ValidationError::MissingColumn { column_name: column_name.to_string() });
+            }
+            if referenced_keys.is_err() {
+                report.push(ValidationError::MissingColumn { column_name: foreign_key.referenced() });
+            }
+            return Ok(());
+        }
+    };
+    if keys.dtype() != referenced_keys.dtype() {
+        report.push(ValidationError::TypeMismatch {
+            column_name: column_name.to_string(),
+            expected_type: format!("{:?}", referenced_keys.dtype()),
+            actual_type: format!("{:?}", keys.dtype()),
+        });
+        return Ok(());
+    }
+
+    let orphans = df
+        .clone()
+        .lazy()
+        .select([col(column_name).alias(KEY)])
+        .drop_nulls(None)
+        .join(
+            referenced.clone().lazy().select([col(foreign_key.column).alias(KEY)]),
+            [col(KEY)],
+            [col(KEY)],
+            JoinArgs::new(JoinType::Anti),
+        )
+        .group_by_stable([col(KEY)])
+        .agg([len().alias(KEY_COUNT)])
+        .collect()?;
+
+    if orphans.height() == 0 {
+        return Ok(());
+    }
+
+    let orphan_count = orphans
+        .column(KEY_COUNT)?
+        .as_materialized_series()
+        .sum::<u64>()? as usize;
+    let values = orphans.column(KEY)?;
+    let sample = (0..orphans.height().min(MAX_SAMPLE_ROWS))
+        .map(|row| Ok(format_value(values.get(row)?)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    report.push(ValidationError::OrphanedKeys {
+        column_name: column_name.to_string(),
+        referenced: foreign_key.referenced(),
+        orphan_count,
+        sample,
+    });
+    Ok(())
+}
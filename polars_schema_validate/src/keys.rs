@@ -0,0 +1,77 @@
+use polars::prelude::*;
+
+use crate::error::ValidationError;
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+use crate::rows::{format_value, MAX_SAMPLE_ROWS};
+
+/// Name of the temporary column holding the number of rows per key.
+const KEY_COUNT: &str = "__polars_schema_key_count";
+
+/// Checks unique fields and the primary key for duplicate values.
+///
+/// Keys with a missing column are skipped, missing columns are reported by schema
+/// validation.
+pub(crate) fn check_keys(
+    fields: &[FieldSpec],
+    primary_key: Option<&[&'static str]>,
+    df: &DataFrame,
+    report: &mut ValidationReport,
+) {
+    let unique = fields.iter().filter(|field| field.unique).map(|field| vec![field.name]);
+    let keys = unique.chain(primary_key.map(<[_]>::to_vec));
+
+    for key in keys {
+        if key.iter().any(|column| df.column(column).is_err()) {
+            continue;
+        }
+        if let Err(err) = check_duplicates(df, &key, report) {
+            report.push(err.into());
+        }
+    }
+}
+
+/// Reports the key values that occur in more than one row, in order of first occurrence.
+///
+/// Rows with a null in any key column are ignored, like SQL `UNIQUE` constraints do:
+/// nulls are reported by the nullability checks of non-`Option` fields.
+pub(crate) fn check_duplicates(df: &DataFrame, key: &[&str], report: &mut ValidationReport) -> PolarsResult<()> {
+    let key_columns: Vec<_> = key.iter().map(|column| col(*column)).collect();
+    let complete = key_columns
+        .iter()
+        .map(|column| column.clone().is_not_null())
+        .reduce(|acc, expr| acc.and(expr))
+        .unwrap_or(lit(true));
+    let duplicates = df
+        .clone()
+        .lazy()
+        .filter(complete)
+        .group_by_stable(key_columns)
+        .agg([len().alias(KEY_COUNT)])
+        .filter(col(KEY_COUNT).gt(lit(1)))
+        .collect()?;
+
+    if duplicates.height() == 0 {
+        return Ok(());
+    }
+
+    let sample = (0..duplicates.height().min(MAX_SAMPLE_ROWS))
+        .map(|row| {
+            let values = key
+                .iter()
+                .map(|column| Ok(format_value(duplicates.column(column)?.get(row)?)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(match values.as_slice() {
+                [value] => value.clone(),
+                _ => format!("({})", values.join(", ")),
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    report.push(ValidationError::DuplicateKeys {
+        columns: key.iter().map(|column| column.to_string()).collect(),
+        duplicate_count: duplicates.height(),
+        sample,
+    });
+    Ok(())
+}
@@ -1,9 +1,38 @@
 use polars::prelude::*;
 
-pub use polars_schema_derive::PolarsSchema;
+pub use polars_schema_derive::{PolarsEnum, PolarsSchema};
 
+mod check;
+mod conform;
+mod constraint;
+mod dtype;
 mod error;
+mod field;
+mod foreign_key;
+mod keys;
+mod partition;
+mod report;
+mod rows;
+mod sorted;
+mod statistic;
+#[cfg(feature = "chrono")]
+mod timeseries;
+mod validation;
+mod validator;
+pub use check::Check;
+pub use conform::CastMode;
+pub use constraint::{Constraint, ConstraintValue, Tolerance, Tolerances};
+pub use dtype::{enum_dtype, PolarsDtype, PolarsEnum};
 pub use error::{ValidationError, Result};
+pub use field::FieldSpec;
+pub use foreign_key::{check_foreign_keys, ForeignKey};
+pub use partition::VIOLATIONS_COLUMN;
+pub use report::ValidationReport;
+pub use sorted::{SortOrder, Sortedness};
+pub use statistic::{Expectation, Statistic};
+#[cfg(feature = "chrono")]
+pub use timeseries::TimeSeries;
+pub use validator::Validator;
 
 /// Trait for validating Polars DataFrames against a schema derived from Rust structs.
 ///
@@ -30,9 +59,201 @@ pub use error::{ValidationError, Result};
 ///
 /// assert!(Person::validate(&df).is_ok());
 /// ```
+///
+/// # Compile errors
+///
+/// The derive macro never guesses a dtype: field types that do not implement
+/// [`PolarsDtype`] are rejected at compile time with an error pointing at the field type.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Scores {
+///     values: std::collections::HashMap<String, i32>,
+/// }
+/// ```
+///
+/// Invalid `#[polars(dtype = "...")]` overrides are rejected as well.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Event {
+///     #[polars(dtype = "Datetime(hours)")]
+///     at: i64,
+/// }
+/// ```
+///
+/// Time zones are checked against the IANA time zone database.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Event {
+///     #[polars(dtype = "Datetime(ms, Not/AZone)")]
+///     at: i64,
+/// }
+/// ```
+///
+/// Constraints must fit the field type, and regular expressions must compile.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(regex = "^[0-9]+$")]
+///     id: i64,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(min = 0)]
+///     name: Option<String>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(one_of(1, 2))]
+///     tier: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(one_of(1, "gold"))]
+///     tier: i64,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(min = "2020-01-01")]
+///     opened: chrono::NaiveDate,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Account {
+///     #[polars(regex = "^[A-Z")]
+///     code: String,
+/// }
+/// ```
+///
+/// Numeric statistics require a numeric field.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Payment {
+///     #[polars(mean(min = 1))]
+///     currency: String,
+/// }
+/// ```
+///
+/// `not_null`, `not_nan`, `finite` and `category` only set the tolerance of a check
+/// implied by the field, so they require `mostly` or `max_failures`.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// struct Reading {
+///     #[polars(not_nan)]
+///     value: f64,
+/// }
+/// ```
+///
+/// Only structs with named fields are supported.
+///
+/// ```compile_fail
+/// use polars_schema_validate::PolarsSchema;
+///
+/// #[derive(PolarsSchema)]
+/// enum Status {
+///     Active,
+///     Inactive,
+/// }
+/// ```
 pub trait PolarsSchema {
     /// Returns the expected schema as a vector of (column_name, data_type) pairs.
     fn schema() -> Vec<(&'static str, DataType)>;
+
+    /// Returns the expected columns with their full specification.
+    ///
+    /// The default implementation builds nullable fields from [`schema`](Self::schema).
+    /// The derive macro marks every field that is not an `Option<T>` as non-nullable.
+    fn fields() -> Vec<FieldSpec> {
+        Self::schema()
+            .into_iter()
+            .map(|(name, dtype)| FieldSpec::new(name, dtype))
+            .collect()
+    }
+
+    /// Returns the column names of the primary key, if one is declared.
+    ///
+    /// The derive macro generates it from `#[polars(primary_key(order_id, line_no))]`
+    /// on the struct. Validation reports key values that occur in more than one row.
+    fn primary_key() -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// Returns the struct-level checks spanning several columns.
+    ///
+    /// The derive macro generates them from `#[polars(check = ..., name = ...)]` on the
+    /// struct. Validation reports rows failing a check as [`ValidationError::FailedCheck`].
+    fn checks() -> Vec<Check> {
+        Vec::new()
+    }
+
+    /// Returns the custom validation functions run on the whole DataFrame.
+    ///
+    /// The derive macro generates them from `#[polars(validate_with = "...")]` on the
+    /// struct. Errors they return are merged into the validation report.
+    fn validators() -> Vec<Validator<DataFrame>> {
+        Vec::new()
+    }
+
+    /// Returns the inclusive `(min, max)` bounds on the number of rows.
+    ///
+    /// The derive macro generates them from `#[polars(rows(min = 1, max = 10_000))]` on
+    /// the struct. Validation reports a row count outside the bounds as
+    /// [`ValidationError::RowCount`].
+    fn row_bounds() -> (Option<usize>, Option<usize>) {
+        (None, None)
+    }
+
+    /// Returns the regular time grid the DataFrame must follow, if declared.
+    ///
+    /// The derive macro generates it from
+    /// `#[polars(timeseries(time = "ts", every = "1m", by = "sensor_id", max_gap = "5m"))]`
+    /// on the struct. Validation reports duplicate timestamps, timestamps off the grid
+    /// and gaps, see [`TimeSeries`].
+    #[cfg(feature = "chrono")]
+    fn timeseries() -> Option<TimeSeries> {
+        None
+    }
     
     /// Validates a DataFrame against the struct's schema.
     ///
@@ -41,28 +262,9 @@ pub trait PolarsSchema {
     ///
     /// # Returns
     /// * `Ok(())` if the DataFrame matches the schema
-    /// * `Err(ValidationError)` with details about the mismatch
+    /// * `Err(ValidationError)` with details about the first mismatch
     fn validate(df: &DataFrame) -> Result<()> {
-        let df_schema = df.schema();
-        let expected_schema = Self::schema();
-        
-        for (name, expected_type) in expected_schema {
-            match df_schema.get(name) {
-                None => return Err(ValidationError::MissingColumn {
-                    column_name: name.to_string(),
-                }),
-                Some(actual_type) => {
-                    if actual_type != &expected_type {
-                        return Err(ValidationError::TypeMismatch {
-                            column_name: name.to_string(),
-                            expected_type: format!("{:?}", expected_type),
-                            actual_type: format!("{:?}", actual_type),
-                        });
-                    }
-                }
-            }
-        }
-        Ok(())
+        Self::validate_all(df).into_first_error()
     }
     
     /// Validates a DataFrame against the struct's schema in strict mode.
@@ -75,49 +277,183 @@ pub trait PolarsSchema {
     ///
     /// # Returns
     /// * `Ok(())` if the DataFrame exactly matches the schema
-    /// * `Err(ValidationError)` with details about the mismatch
+    /// * `Err(ValidationError)` with details about the first mismatch
     fn validate_strict(df: &DataFrame) -> Result<()> {
-        let df_schema = df.schema();
-        let expected_schema = Self::schema();
-        
-        // Check column count
-        if df_schema.len() != expected_schema.len() {
-            return Err(ValidationError::ColumnCountMismatch {
-                expected_count: expected_schema.len(),
-                actual_count: df_schema.len(),
-            });
-        }
-        
-        // Validate all expected columns exist with correct types
-        for (name, expected_type) in &expected_schema {
-            match df_schema.get(*name) {
-                None => return Err(ValidationError::MissingColumn {
-                    column_name: name.to_string(),
-                }),
-                Some(actual_type) => {
-                    if actual_type != expected_type {
-                        return Err(ValidationError::TypeMismatch {
-                            column_name: name.to_string(),
-                            expected_type: format!("{:?}", expected_type),
-                            actual_type: format!("{:?}", actual_type),
-                        });
-                    }
-                }
-            }
+        validation::check_column_count(&Self::fields(), df.schema())?;
+        Self::validate_strict_all(df).into_first_error()
+    }
+
+    /// Validates a DataFrame against the struct's schema, collecting every error.
+    ///
+    /// Unlike [`validate`](Self::validate), this does not stop at the first problem:
+    /// every missing column and type mismatch is recorded in the returned report, as
+    /// well as every failing row-level check (null values in non-nullable columns, NaN
+    /// and infinite floats, invalid categories, out-of-order rows, value constraints and
+    /// struct-level checks) with a sample of the failing rows, every column statistic
+    /// outside its bounds, every duplicated unique column or primary key, every duplicate
+    /// timestamp and gap of a time series, and the errors of custom validators. Extra
+    /// columns are allowed.
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to validate
+    ///
+    /// # Returns
+    /// * A [`ValidationReport`] that is empty if the DataFrame matches the schema
+    fn validate_all(df: &DataFrame) -> ValidationReport {
+        validation::validate_frame::<Self>(df, false)
+    }
+
+    /// Validates a DataFrame against the struct's schema in strict mode, collecting every error.
+    ///
+    /// In addition to the checks of [`validate_all`](Self::validate_all), every column
+    /// of the DataFrame that is not part of the schema is reported as
+    /// [`ValidationError::UnexpectedColumn`].
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to validate
+    ///
+    /// # Returns
+    /// * A [`ValidationReport`] that is empty if the DataFrame exactly matches the schema
+    fn validate_strict_all(df: &DataFrame) -> ValidationReport {
+        validation::validate_frame::<Self>(df, true)
+    }
+
+    /// Splits a DataFrame into rows that pass every row-level check and quarantined rows.
+    ///
+    /// Row-level checks are the null checks of non-nullable fields, the NaN and infinity
+    /// checks of float fields, the category checks of enum fields, ordering checks, value
+    /// constraints and struct-level checks; duplicate keys and custom validators are not
+    /// row-level. Quarantined rows keep their columns and get an extra
+    /// [`VIOLATIONS_COLUMN`] listing the checks each row violated, so a batch can be
+    /// loaded without the few bad rows holding up the rest.
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to partition
+    ///
+    /// # Returns
+    /// * `Ok((valid, quarantine))` with the passing rows and the failing rows
+    /// * `Err(ValidationError)` if a column is missing or has the wrong type, since
+    ///   those affect every row, or [`ValidationError::UnexpectedColumn`] if `df`
+    ///   already has a [`VIOLATIONS_COLUMN`]
+    fn partition(df: &DataFrame) -> Result<(DataFrame, DataFrame)> {
+        partition::partition_frame(&Self::fields(), &Self::checks(), df)
+    }
+
+    /// Validates a DataFrame and marks its sorted columns for Polars.
+    ///
+    /// Like [`validate`](Self::validate), and on success sets Polars' sorted flag on
+    /// columns declared with `sorted = "..."` or `strictly_increasing`, so that
+    /// operations such as `join_asof` or `group_by_dynamic` can rely on it. Columns sorted
+    /// only within groups, columns whose ordering has a tolerance and columns containing
+    /// nulls are not flagged.
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to validate and mark
+    fn validate_and_mark_sorted(df: &mut DataFrame) -> Result<()> {
+        Self::validate(df)?;
+        sorted::mark_sorted(&Self::fields(), df)
+    }
+
+    /// Validates the output schema of a LazyFrame plan without collecting it.
+    ///
+    /// The plan's schema is resolved with `collect_schema`, so column presence and
+    /// dtypes are checked before any data is read. Checks that need the data, such as
+    /// null values and invalid categories, are not performed. A declared row count is
+    /// checked once the schema matches, by running the plan with only a `count` of its
+    /// rows; this still evaluates the plan's filters and joins.
+    ///
+    /// # Arguments
+    /// * `lf` - The LazyFrame to validate
+    ///
+    /// # Returns
+    /// * `Ok(())` if the plan's schema matches the schema
+    /// * `Err(ValidationError)` with details about the first mismatch, or
+    ///   [`ValidationError::Polars`] if the plan's schema cannot be resolved
+    fn validate_lazy(lf: &mut LazyFrame) -> Result<()> {
+        let lf_schema = lf.collect_schema()?;
+        let mut report = ValidationReport::new();
+        validation::check_columns(&Self::fields(), &lf_schema, false, &mut report);
+        // Running the plan is only worth it once the schema matches
+        if report.is_valid() {
+            validation::check_lazy_row_count(lf, Self::row_bounds(), &mut report)?;
         }
-        
-        // Check for unexpected columns
-        let expected_names: std::collections::HashSet<_> = 
-            expected_schema.iter().map(|(name, _)| *name).collect();
-        
-        for (col_name, _) in df_schema.iter() {
-            if !expected_names.contains(col_name.as_str()) {
-                return Err(ValidationError::UnexpectedColumn {
-                    column_name: col_name.to_string(),
-                });
-            }
+        report.into_first_error()
+    }
+
+    /// Validates the output schema of a LazyFrame plan in strict mode without collecting it.
+    ///
+    /// In strict mode, the plan must produce exactly the same columns as the schema,
+    /// no more, no less.
+    ///
+    /// # Arguments
+    /// * `lf` - The LazyFrame to validate
+    ///
+    /// # Returns
+    /// * `Ok(())` if the plan's schema exactly matches the schema
+    /// * `Err(ValidationError)` with details about the first mismatch, or
+    ///   [`ValidationError::Polars`] if the plan's schema cannot be resolved
+    fn validate_lazy_strict(lf: &mut LazyFrame) -> Result<()> {
+        let fields = Self::fields();
+        let lf_schema = lf.collect_schema()?;
+        validation::check_column_count(&fields, &lf_schema)?;
+        let mut report = ValidationReport::new();
+        validation::check_columns(&fields, &lf_schema, true, &mut report);
+        // Running the plan is only worth it once the schema matches
+        if report.is_valid() {
+            validation::check_lazy_row_count(lf, Self::row_bounds(), &mut report)?;
         }
-        
-        Ok(())
+        report.into_first_error()
+    }
+
+    /// Conforms a DataFrame to the struct's schema.
+    ///
+    /// Selects the schema's columns in declaration order, drops unexpected columns,
+    /// fills missing columns of `Option<T>` fields with nulls and casts columns with a
+    /// different dtype. Casts that would lose data fail, see [`conform_with`](Self::conform_with)
+    /// for lenient casting. Data checks such as null values are not performed, use
+    /// [`validate`](Self::validate) on the result for those.
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to conform
+    ///
+    /// # Returns
+    /// * `Ok(DataFrame)` with exactly the schema's columns and dtypes
+    /// * `Err(ValidationError)` for a missing required column, an impossible cast or a
+    ///   cast that would lose data ([`ValidationError::LossyCast`])
+    fn conform(df: &DataFrame) -> Result<DataFrame> {
+        Self::conform_with(df, CastMode::Strict)
+    }
+
+    /// Conforms a DataFrame to the struct's schema with the given cast mode.
+    ///
+    /// With [`CastMode::Lenient`], values that cannot be represented in the target dtype
+    /// become null instead of failing.
+    ///
+    /// # Arguments
+    /// * `df` - The DataFrame to conform
+    /// * `mode` - How to handle casts that would lose data
+    fn conform_with(df: &DataFrame, mode: CastMode) -> Result<DataFrame> {
+        conform::conform_frame(&Self::fields(), df, mode)
+    }
+
+    /// Conforms a LazyFrame plan to the struct's schema.
+    ///
+    /// Like [`conform`](Self::conform), but returns a plan. Lossy casts cannot be
+    /// detected before the data is read, so they make the plan fail when collected, with
+    /// a Polars error carrying the [`ValidationError::LossyCast`] message.
+    ///
+    /// # Arguments
+    /// * `lf` - The LazyFrame to conform
+    fn conform_lazy(lf: LazyFrame) -> Result<LazyFrame> {
+        Self::conform_lazy_with(lf, CastMode::Strict)
+    }
+
+    /// Conforms a LazyFrame plan to the struct's schema with the given cast mode.
+    ///
+    /// # Arguments
+    /// * `lf` - The LazyFrame to conform
+    /// * `mode` - How to handle casts that would lose data
+    fn conform_lazy_with(lf: LazyFrame, mode: CastMode) -> Result<LazyFrame> {
+        conform::conform_plan(&Self::fields(), lf, mode)
     }
-}
\ No newline at end of file
+}
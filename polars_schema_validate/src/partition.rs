@@ -0,0 +1,72 @@
+use polars::prelude::*;
+
+use crate::check::Check;
+use crate::error::{Result, ValidationError};
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+use crate::rows;
+use crate::validation;
+
+/// Name of the column added to quarantined rows by
+/// [`PolarsSchema::partition`](crate::PolarsSchema::partition).
+///
+/// It is a `List(String)` column with one `"<column>: <check>"` entry per violated
+/// check, e.g. `["age: min = 0", "name: not_null"]`. Struct-level checks are listed by
+/// name only.
+pub const VIOLATIONS_COLUMN: &str = "_violations";
+
+/// Splits a DataFrame into rows passing every row-level check and quarantined rows.
+///
+/// Schema errors such as missing columns affect every row, so they are returned as an
+/// error instead. So is an existing [`VIOLATIONS_COLUMN`], which would be overwritten.
+pub(crate) fn partition_frame(
+    fields: &[FieldSpec],
+    struct_checks: &[Check],
+    df: &DataFrame,
+) -> Result<(DataFrame, DataFrame)> {
+    if df.schema().contains(VIOLATIONS_COLUMN) {
+        return Err(ValidationError::UnexpectedColumn {
+            column_name: VIOLATIONS_COLUMN.to_string(),
+        });
+    }
+
+    let mut report = ValidationReport::new();
+    validation::check_columns(fields, df.schema(), false, &mut report);
+    report.into_first_error()?;
+
+    let checks = rows::row_checks(fields, struct_checks, df.schema());
+    let failing: Vec<_> = checks
+        .iter()
+        .enumerate()
+        .map(|(i, check)| check.failing().alias(format!("check_{}", i)))
+        .collect();
+    let masks = df.clone().lazy().select(failing).collect()?;
+
+    let mut violations: Vec<Vec<String>> = vec![Vec::new(); df.height()];
+    for (check, mask) in checks.iter().zip(masks.get_columns()) {
+        let mask = mask.as_materialized_series().bool()?;
+        for (row, failing) in mask.into_iter().enumerate() {
+            if failing == Some(true) {
+                violations[row].push(check.label());
+            }
+        }
+    }
+
+    let invalid: BooleanChunked = violations.iter().map(|row| !row.is_empty()).collect();
+    let valid = df.filter(&!&invalid)?;
+    let mut quarantine = df.filter(&invalid)?;
+
+    let lists: Vec<Series> = violations
+        .iter()
+        .filter(|row| !row.is_empty())
+        .map(|row| Series::new(PlSmallStr::EMPTY, row))
+        .collect();
+    let violations = if lists.is_empty() {
+        Series::new_empty(VIOLATIONS_COLUMN.into(), &DataType::List(Box::new(DataType::String)))
+    } else {
+        Series::new(VIOLATIONS_COLUMN.into(), lists)
+    };
+    quarantine.with_column(violations)?;
+
+    Ok((valid, quarantine))
+}
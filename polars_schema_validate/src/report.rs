@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::error::ValidationError;
+
+/// Collection of every validation error found in a single pass over a DataFrame.
+///
+/// Returned by [`PolarsSchema::validate_all`](crate::PolarsSchema::validate_all) and
+/// [`PolarsSchema::validate_strict_all`](crate::PolarsSchema::validate_strict_all),
+/// which keep going after the first problem instead of returning early.
+///
+/// Failures of constraints with a tolerance (`mostly`, `max_failures`) that stay within
+/// budget do not make the report invalid, they are kept in [`tolerated`](Self::tolerated).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    errors: Vec<ValidationError>,
+    tolerated: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a validation error.
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Records the observed failures of a tolerant constraint that are within budget.
+    pub fn push_tolerated(&mut self, error: ValidationError) {
+        self.tolerated.push(error);
+    }
+
+    /// Returns the observed failure rates of tolerant constraints that are within budget.
+    pub fn tolerated(&self) -> &[ValidationError] {
+        &self.tolerated
+    }
+
+    /// Returns `true` if no errors were recorded.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if no errors were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the recorded errors in the order they were found.
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Iterates over the recorded errors.
+    pub fn iter(&self) -> std::slice::Iter<'_, ValidationError> {
+        self.errors.iter()
+    }
+
+    /// Consumes the report, returning `Ok(())` if it is valid or the report itself otherwise.
+    pub fn into_result(self) -> std::result::Result<(), ValidationReport> {
+        if self.is_valid() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Consumes the report, returning `Ok(())` if it is valid or the first recorded error otherwise.
+    pub fn into_first_error(self) -> crate::Result<()> {
+        match self.errors.into_iter().next() {
+            None => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.errors.is_empty() {
+            return write!(f, "DataFrame is valid");
+        }
+        write!(f, "DataFrame failed validation with {} error(s):", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+impl IntoIterator for ValidationReport {
+    type Item = ValidationError;
+    type IntoIter = std::vec::IntoIter<ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationReport {
+    type Item = &'a ValidationError;
+    type IntoIter = std::slice::Iter<'a, ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl FromIterator<ValidationError> for ValidationReport {
+    fn from_iter<I: IntoIterator<Item = ValidationError>>(iter: I) -> Self {
+        Self {
+            errors: iter.into_iter().collect(),
+            tolerated: Vec::new(),
+        }
+    }
+}
+
+impl Extend<ValidationError> for ValidationReport {
+    fn extend<I: IntoIterator<Item = ValidationError>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
@@ -0,0 +1,441 @@
+use polars::prelude::*;
+
+use crate::check::Check;
+use crate::constraint::{Constraint, Tolerance, Tolerances};
+use crate::error::ValidationError;
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+
+/// Maximum number of failing rows or duplicate keys kept in the sample of an error.
+pub(crate) const MAX_SAMPLE_ROWS: usize = 10;
+
+/// Name of the temporary row index column used to locate failing rows.
+const ROW_INDEX: &str = "__polars_schema_row_index";
+
+/// How the failing rows of a [`RowCheck`] are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CheckKind {
+    /// Nulls in a non-`Option` field, reported as [`ValidationError::NullValues`]
+    NotNull,
+    /// Values that are not categories, reported as [`ValidationError::InvalidCategory`]
+    Category,
+    /// A value constraint, reported as [`ValidationError::ConstraintViolation`]
+    Constraint,
+    /// Any other column-level check, reported as [`ValidationError::InvalidRows`]
+    Values,
+    /// A struct-level check, reported as [`ValidationError::FailedCheck`]
+    Struct,
+}
+
+/// A row-level check: a boolean expression that must hold for every row.
+pub(crate) struct RowCheck {
+    /// Column (or dotted path) reported in errors, `None` for struct-level checks
+    pub column_name: Option<String>,
+    /// Name of the check reported in errors, e.g. `not_null` or `min = 0`
+    pub check: String,
+    /// Error variant the failing rows are reported as
+    pub kind: CheckKind,
+    /// `true` for rows passing the check, null counts as passing
+    pub valid: Expr,
+    /// Value shown in samples of failing rows, `None` for struct-level checks
+    pub value: Option<Expr>,
+    /// Failures allowed before the check fails, `None` if no row may fail
+    pub tolerance: Option<Tolerance>,
+}
+
+impl RowCheck {
+    /// Boolean expression that is `true` for rows failing the check.
+    pub fn failing(&self) -> Expr {
+        self.valid.clone().fill_null(lit(true)).not()
+    }
+
+    /// Restricts the check to rows where `present` is `true`, e.g. where the parent
+    /// struct of a nested field is not null.
+    fn within(self, present: Expr) -> Self {
+        Self {
+            valid: present.not().or(self.valid),
+            ..self
+        }
+    }
+
+    /// Label of the check, prefixed with its column for column-level checks.
+    pub fn label(&self) -> String {
+        match &self.column_name {
+            Some(column_name) => format!("{}: {}", column_name, self.check),
+            None => self.check.clone(),
+        }
+    }
+
+    /// Reports a Polars error raised while evaluating the check, naming the check.
+    fn polars_error(&self, err: PolarsError) -> ValidationError {
+        ValidationError::Polars {
+            message: format!("{}: {}", self.label(), err),
+        }
+    }
+}
+
+/// Builds the row-level checks for all fields: nullability, NaN and infinite values of
+/// float fields, categories of enum fields, ordering and value constraints, applied
+/// recursively to the fields of nested structs and the items of lists and arrays,
+/// followed by the struct-level checks.
+///
+/// Columns that are missing are skipped, and so are dtype-dependent checks of columns
+/// with an unexpected dtype and struct-level checks referencing a missing column; they
+/// are reported by schema validation.
+pub(crate) fn row_checks(fields: &[FieldSpec], struct_checks: &[Check], df_schema: &Schema) -> Vec<RowCheck> {
+    let mut checks = Vec::new();
+
+    for field in fields {
+        let Some(dtype) = df_schema.get(field.name) else {
+            continue;
+        };
+        let column = |name: &str| df_schema.contains(name).then(|| col(name));
+        field_checks(field, field.name, col(field.name), dtype, &column, &mut checks);
+    }
+
+    for check in struct_checks {
+        let root_names = check.expr.clone().meta().root_names();
+        if root_names.iter().all(|name| df_schema.contains(name)) {
+            checks.push(RowCheck {
+                column_name: None,
+                check: check.name.to_string(),
+                kind: CheckKind::Struct,
+                valid: check.expr.clone(),
+                value: None,
+                tolerance: None,
+            });
+        }
+    }
+
+    checks
+}
+
+/// Adds the checks of a single field whose values are `value`, reported under `path`.
+///
+/// `dtype` is the dtype found in the data and `sibling` resolves the other fields of
+/// the same struct (or the other columns at the top level), used as sort groups.
+fn field_checks(
+    field: &FieldSpec,
+    path: &str,
+    value: Expr,
+    dtype: &DataType,
+    sibling: &dyn Fn(&str) -> Option<Expr>,
+    checks: &mut Vec<RowCheck>,
+) {
+    let tolerances = &field.tolerances;
+    if !field.nullable || tolerances.not_null.is_some() {
+        checks.push(RowCheck {
+            column_name: Some(path.to_string()),
+            check: "not_null".to_string(),
+            kind: CheckKind::NotNull,
+            valid: value.clone().is_not_null(),
+            value: Some(value.clone()),
+            tolerance: tolerances.not_null,
+        });
+    }
+
+    if let (false, DataType::Struct(actual_fields)) = (field.fields.is_empty(), dtype) {
+        let inner = |name: &str| value.clone().struct_().field_by_name(name);
+        let nested_sibling = |name: &str| actual_fields.iter().any(|f| f.name() == name).then(|| inner(name));
+        let mut nested = Vec::new();
+        for nested_field in &field.fields {
+            // Missing fields are reported by schema validation
+            let Some(actual) = actual_fields.iter().find(|f| f.name() == nested_field.name) else {
+                continue;
+            };
+            let nested_path = format!("{}.{}", path, nested_field.name);
+            field_checks(nested_field, &nested_path, inner(nested_field.name), actual.dtype(), &nested_sibling, &mut nested);
+        }
+        // A null `Option<Struct>` does not make its fields fail
+        let present = value.clone().is_not_null();
+        checks.extend(nested.into_iter().map(|check| check.within(present.clone())));
+    }
+
+    let list = match (dtype, &field.dtype) {
+        (DataType::List(inner), DataType::List(expected)) => Some((value.clone(), inner, expected)),
+        (DataType::Array(inner, _), DataType::Array(expected, _)) => Some((value.clone().arr().to_list(), inner, expected)),
+        _ => None,
+    };
+    if let (Some(items), Some((list, item_dtype, expected))) = (&field.items, list) {
+        // The item dtype follows the field's, which may be set with `#[polars(dtype = "...")]`,
+        // and `allow_nan`, `allow_inf` and the value tolerances of a list field apply to its items
+        let items = FieldSpec {
+            dtype: expected.as_ref().clone(),
+            allow_nan: items.allow_nan || field.allow_nan,
+            allow_inf: items.allow_inf || field.allow_inf,
+            tolerances: Tolerances {
+                not_nan: tolerances.not_nan,
+                finite: tolerances.finite,
+                category: tolerances.category,
+                ..items.tolerances
+            },
+            ..items.as_ref().clone()
+        };
+        // Items are checked within each list, where `col("")` is the current item
+        let mut item_checks = Vec::new();
+        field_checks(&items, &format!("{}[]", path), col(""), item_dtype, &|_| None, &mut item_checks);
+        checks.extend(item_checks.into_iter().map(|check| RowCheck {
+            valid: list.clone().list().eval(check.valid.clone().fill_null(lit(true))).list().all(),
+            // Samples show the failing items of each list
+            value: check.value.clone().map(|value| list.clone().list().eval(value.filter(check.failing()))),
+            ..check
+        }));
+    }
+
+    if dtype != &field.dtype {
+        return;
+    }
+
+    if dtype.is_float() {
+        if !field.allow_nan {
+            checks.push(RowCheck {
+                column_name: Some(path.to_string()),
+                check: "not_nan".to_string(),
+                kind: CheckKind::Values,
+                valid: value.clone().is_nan().not(),
+                value: Some(value.clone()),
+                tolerance: tolerances.not_nan,
+            });
+        }
+        if !field.allow_inf {
+            checks.push(RowCheck {
+                column_name: Some(path.to_string()),
+                check: "finite".to_string(),
+                kind: CheckKind::Values,
+                valid: value.clone().is_infinite().not(),
+                value: Some(value.clone()),
+                tolerance: tolerances.finite,
+            });
+        }
+    }
+
+    // Enum columns are already constrained by their dtype
+    if let Some(categories) = &field.categories {
+        if matches!(dtype, DataType::String | DataType::Categorical(_, _)) {
+            let as_string = value.clone().cast(DataType::String);
+            let valid = categories
+                .iter()
+                .map(|category| as_string.clone().eq(lit(*category)))
+                .reduce(|acc, expr| acc.or(expr))
+                .unwrap_or(lit(false));
+            checks.push(RowCheck {
+                column_name: Some(path.to_string()),
+                check: "category".to_string(),
+                kind: CheckKind::Category,
+                valid,
+                value: Some(value.clone()),
+                tolerance: tolerances.category,
+            });
+        }
+    }
+
+    if let Some(sorted) = &field.sorted {
+        // A missing group column is reported by schema validation
+        let group = sorted.group.map(sibling);
+        if group.as_ref().is_none_or(Option::is_some) {
+            checks.push(RowCheck {
+                column_name: Some(path.to_string()),
+                check: sorted.to_string(),
+                kind: CheckKind::Values,
+                valid: sorted.expr_on(value.clone(), group.flatten()),
+                value: Some(value.clone()),
+                tolerance: tolerances.sorted,
+            });
+        }
+    }
+
+    for constraint in &field.constraints {
+        let (constraint, tolerance) = match constraint {
+            Constraint::Tolerant(constraint, tolerance) => (constraint.as_ref(), Some(*tolerance)),
+            constraint => (constraint, None),
+        };
+        checks.push(RowCheck {
+            column_name: Some(path.to_string()),
+            check: constraint.to_string(),
+            kind: CheckKind::Constraint,
+            valid: constraint.expr_on(value.clone(), dtype),
+            value: Some(value.clone()),
+            tolerance,
+        });
+    }
+}
+
+/// Evaluates row-level checks and reports every failing one, as
+/// [`ValidationError::NullValues`], [`ValidationError::InvalidCategory`],
+/// [`ValidationError::ConstraintViolation`], [`ValidationError::InvalidRows`] or, for
+/// struct-level checks, [`ValidationError::FailedCheck`] depending on its [`CheckKind`].
+///
+/// Checks with a tolerance are always reported as [`ValidationError::FailureRate`], as
+/// an error if the failures exceed the tolerance and as tolerated otherwise.
+///
+/// Failing rows of all checks are counted in a single pass over the DataFrame; the
+/// sample of failing rows is only collected for checks that fail. If Polars cannot
+/// evaluate a check, e.g. a constraint on a column of another dtype, that check is
+/// reported as [`ValidationError::Polars`] and the others are still evaluated.
+pub(crate) fn check_rows(df: &DataFrame, checks: &[RowCheck], report: &mut ValidationReport) {
+    if checks.is_empty() {
+        return;
+    }
+
+    // Struct-level checks are user expressions that may not evaluate against the data,
+    // so each is counted on its own instead of in the batch of column checks
+    let (struct_checks, column_checks): (Vec<_>, Vec<_>) =
+        checks.iter().partition(|check| check.kind == CheckKind::Struct);
+    let mut batches = vec![column_checks];
+    batches.extend(struct_checks.into_iter().map(|check| vec![check]));
+
+    for batch in batches.into_iter().filter(|batch| !batch.is_empty()) {
+        let counts: Vec<PolarsResult<usize>> = match count_failing(df, &batch) {
+            Ok(counts) => counts.into_iter().map(Ok).collect(),
+            // Counted one check at a time, so only the checks Polars cannot evaluate fail
+            Err(_) => batch.iter().map(|&check| Ok(count_failing(df, &[check])?[0])).collect(),
+        };
+
+        for (check, failing_count) in batch.into_iter().zip(counts) {
+            if let Err(err) = failing_count.and_then(|failing_count| report_check(df, check, failing_count, report)) {
+                report.push(check.polars_error(err));
+            }
+        }
+    }
+}
+
+/// Reports a check with `failing_count` failing rows, collecting samples of them.
+fn report_check(
+    df: &DataFrame,
+    check: &RowCheck,
+    failing_count: usize,
+    report: &mut ValidationReport,
+) -> PolarsResult<()> {
+    if failing_count == 0 && check.tolerance.is_none() {
+        return Ok(());
+    }
+    let sample = match failing_count {
+        0 => Vec::new(),
+        _ => sample_failing(df, check)?,
+    };
+    let column_name = check.column_name.clone().unwrap_or_default();
+
+    if let Some(tolerance) = check.tolerance {
+        let error = ValidationError::FailureRate {
+            column_name,
+            check: check.check.clone(),
+            failing_count,
+            row_count: df.height(),
+            tolerance: tolerance.to_string(),
+            sample,
+        };
+        if tolerance.allows(failing_count, df.height()) {
+            report.push_tolerated(error);
+        } else {
+            report.push(error);
+        }
+        return Ok(());
+    }
+
+    let rows = sample.iter().map(|(row, _)| *row).collect();
+    report.push(match check.kind {
+        CheckKind::NotNull => ValidationError::NullValues {
+            column_name,
+            null_count: failing_count,
+            rows,
+        },
+        CheckKind::Category => ValidationError::InvalidCategory {
+            column_name,
+            invalid_count: failing_count,
+            values: distinct_failing(df, check)?,
+            rows,
+        },
+        CheckKind::Constraint => ValidationError::ConstraintViolation {
+            column_name,
+            constraint: check.check.clone(),
+            failing_count,
+            sample,
+        },
+        CheckKind::Values => ValidationError::InvalidRows {
+            column_name,
+            check: check.check.clone(),
+            failing_count,
+            sample,
+        },
+        CheckKind::Struct => ValidationError::FailedCheck {
+            check: check.check.clone(),
+            failing_count,
+            rows,
+        },
+    });
+    Ok(())
+}
+
+/// Counts the failing rows of every check in a single aggregation.
+fn count_failing(df: &DataFrame, checks: &[&RowCheck]) -> PolarsResult<Vec<usize>> {
+    let exprs: Vec<_> = checks
+        .iter()
+        .enumerate()
+        .map(|(i, check)| check.failing().sum().alias(format!("check_{}", i)))
+        .collect();
+    let counts = df.clone().lazy().select(exprs).collect()?;
+
+    counts
+        .get_columns()
+        .iter()
+        .map(|column| Ok(column.get(0)?.extract::<usize>().unwrap_or(0)))
+        .collect()
+}
+
+/// Collects up to [`MAX_SAMPLE_ROWS`] `(row_index, value)` pairs of rows failing the check.
+///
+/// Values are empty for struct-level checks.
+fn sample_failing(df: &DataFrame, check: &RowCheck) -> PolarsResult<Vec<(usize, String)>> {
+    let value = check.value.clone().unwrap_or(lit(NULL));
+    let rows = df
+        .clone()
+        .lazy()
+        .with_row_index(ROW_INDEX, None)
+        // Evaluated before filtering, as predicate pushdown cannot resolve list items
+        .select([col(ROW_INDEX), value.alias("value"), check.failing().alias("failing")])
+        .filter(col("failing"))
+        .limit(MAX_SAMPLE_ROWS as IdxSize)
+        .collect()?;
+
+    let indices = rows.column(ROW_INDEX)?.as_materialized_series().cast(&DataType::UInt64)?;
+    let indices = indices.u64()?;
+    let values = rows.column("value")?;
+
+    indices
+        .into_iter()
+        .enumerate()
+        .map(|(i, index)| {
+            let value = match check.value {
+                Some(_) => format_value(values.get(i)?),
+                None => String::new(),
+            };
+            Ok((index.unwrap_or_default() as usize, value))
+        })
+        .collect()
+}
+
+/// Collects up to [`MAX_SAMPLE_ROWS`] distinct values of rows failing the check, in
+/// order of their first occurrence. The failing items of list item checks are
+/// collected individually.
+fn distinct_failing(df: &DataFrame, check: &RowCheck) -> PolarsResult<Vec<String>> {
+    let value = check.value.clone().unwrap_or(lit(NULL));
+    let values = df
+        .clone()
+        .lazy()
+        .select([value.alias("value"), check.failing().alias("failing")])
+        .filter(col("failing"))
+        .select([col("value").explode().unique_stable()])
+        .limit(MAX_SAMPLE_ROWS as IdxSize)
+        .collect()?;
+
+    let values = values.column("value")?;
+    (0..values.len()).map(|i| Ok(format_value(values.get(i)?))).collect()
+}
+
+/// Formats a value for error messages, strings without quotes.
+pub(crate) fn format_value(value: AnyValue) -> String {
+    match value.get_str() {
+        Some(value) => value.to_string(),
+        None => value.to_string(),
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+
+use polars::prelude::*;
+use polars::series::IsSorted;
+
+use crate::error::Result;
+use crate::field::FieldSpec;
+
+/// Order a column's values must follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Each value is greater than or equal to the previous one (`sorted = "asc"`)
+    Ascending,
+    /// Each value is less than or equal to the previous one (`sorted = "desc"`)
+    Descending,
+    /// Each value is greater than the previous one (`strictly_increasing`)
+    StrictlyIncreasing,
+}
+
+/// Ordering constraint on a column, optionally within groups of another column.
+///
+/// Generated by field attributes of `#[derive(PolarsSchema)]`:
+///
+/// | Attribute | Sortedness |
+/// |-----------|------------|
+/// | `#[polars(sorted = "asc")]` | `Ascending` |
+/// | `#[polars(sorted = "desc")]` | `Descending` |
+/// | `#[polars(strictly_increasing)]` | `StrictlyIncreasing` |
+/// | `#[polars(sorted_within(group = "ticker"))]` | `Ascending` within each `ticker` |
+///
+/// Null values are skipped, like in value constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sortedness {
+    pub order: SortOrder,
+    /// Column whose groups are each sorted, `None` if the whole column is sorted
+    pub group: Option<&'static str>,
+}
+
+impl Sortedness {
+    /// Returns a boolean expression that is `true` for rows in order with the previous
+    /// non-null row.
+    pub fn expr(&self, column: &str) -> Expr {
+        self.expr_on(col(column), self.group.map(col))
+    }
+
+    /// Like [`expr`](Self::expr), but checks the values of an expression within the
+    /// groups of `group`, such as fields of a struct column.
+    pub fn expr_on(&self, value: Expr, group: Option<Expr>) -> Expr {
+        let mut previous = value.clone().shift(lit(1)).fill_null_with_strategy(FillNullStrategy::Forward(None));
+        if let Some(group) = group {
+            previous = previous.over([group]);
+        }
+        match self.order {
+            SortOrder::Ascending => value.gt_eq(previous),
+            SortOrder::Descending => value.lt_eq(previous),
+            SortOrder::StrictlyIncreasing => value.gt(previous),
+        }
+    }
+}
+
+impl fmt::Display for Sortedness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.order {
+            SortOrder::Ascending => write!(f, "sorted = \"asc\"")?,
+            SortOrder::Descending => write!(f, "sorted = \"desc\"")?,
+            SortOrder::StrictlyIncreasing => write!(f, "strictly_increasing")?,
+        }
+        if let Some(group) = self.group {
+            write!(f, " within {}", group)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sets Polars' sorted flag on columns that are sorted as a whole and contain no nulls.
+///
+/// Must only be called after the ordering has been validated. Columns whose ordering
+/// has a tolerance may have passed with rows out of order, so they are not flagged.
+pub(crate) fn mark_sorted(fields: &[FieldSpec], df: &mut DataFrame) -> Result<()> {
+    for field in fields {
+        let Some(Sortedness { order, group: None }) = field.sorted else {
+            continue;
+        };
+        if field.tolerances.sorted.is_some() {
+            continue;
+        }
+        let Ok(column) = df.column(field.name) else {
+            continue;
+        };
+        if column.null_count() > 0 {
+            continue;
+        }
+
+        let mut series = column.as_materialized_series().clone();
+        series.set_sorted_flag(match order {
+            SortOrder::Ascending | SortOrder::StrictlyIncreasing => IsSorted::Ascending,
+            SortOrder::Descending => IsSorted::Descending,
+        });
+        df.with_column(series)?;
+    }
+    Ok(())
+}
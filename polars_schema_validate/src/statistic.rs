@@ -0,0 +1,176 @@
+use std::fmt;
+
+use polars::prelude::*;
+
+use crate::error::ValidationError;
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+
+/// A column-level statistic that can be bounded by an [`Expectation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Statistic {
+    /// Fraction of null values
+    NullFraction,
+    /// Number of distinct values, null counting as a value
+    DistinctCount,
+    /// Mean of the non-null values
+    Mean,
+    /// Sum of the values
+    Sum,
+    /// Sample standard deviation of the non-null values
+    Std,
+    /// Smallest value
+    Min,
+    /// Largest value
+    Max,
+    /// Quantile of the values with linear interpolation, e.g. `Quantile(0.99)`
+    Quantile(f64),
+}
+
+impl Statistic {
+    /// Returns `true` for statistics that are only computed on numeric columns.
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Statistic::NullFraction | Statistic::DistinctCount)
+    }
+
+    /// Returns an expression computing the statistic of the column as a `Float64`.
+    pub fn expr(&self, column: &str) -> Expr {
+        let value = match self {
+            Statistic::NullFraction => {
+                col(column).null_count().cast(DataType::Float64) / len().cast(DataType::Float64)
+            }
+            Statistic::DistinctCount => col(column).n_unique(),
+            Statistic::Mean => col(column).mean(),
+            Statistic::Sum => col(column).sum(),
+            Statistic::Std => col(column).std(1),
+            Statistic::Min => col(column).min(),
+            Statistic::Max => col(column).max(),
+            Statistic::Quantile(quantile) => col(column).quantile(lit(*quantile), QuantileMethod::Linear),
+        };
+        value.cast(DataType::Float64)
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Statistic::NullFraction => write!(f, "null_fraction"),
+            Statistic::DistinctCount => write!(f, "distinct"),
+            Statistic::Mean => write!(f, "mean"),
+            Statistic::Sum => write!(f, "sum"),
+            Statistic::Std => write!(f, "std"),
+            Statistic::Min => write!(f, "min_value"),
+            Statistic::Max => write!(f, "max_value"),
+            Statistic::Quantile(quantile) => write!(f, "quantile({})", quantile),
+        }
+    }
+}
+
+/// Bounds on a column [`Statistic`], checked once per DataFrame rather than per row.
+///
+/// Generated by field attributes of `#[derive(PolarsSchema)]`:
+///
+/// | Attribute | Expectation |
+/// |-----------|-------------|
+/// | `#[polars(max_null_fraction = 0.1)]` | `NullFraction` at most `0.1` |
+/// | `#[polars(distinct(min = 50, max = 300))]` | `DistinctCount` between `50` and `300` |
+/// | `#[polars(mean(min = 10.0, max = 500.0))]` | `Mean` between `10.0` and `500.0` |
+/// | `#[polars(sum(max = 1e6))]`, `#[polars(std(max = 5))]` | `Sum`, `Std` at most the bound |
+/// | `#[polars(min_value(min = 0))]`, `#[polars(max_value(max = 100))]` | `Min`, `Max` within bounds |
+/// | `#[polars(quantile(0.99, max = 1000))]` | `Quantile(0.99)` at most `1000` |
+///
+/// Numeric statistics (all but the null fraction and distinct count) only apply to
+/// numeric columns; the derive rejects them on other fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    pub statistic: Statistic,
+    /// Inclusive lower bound
+    pub min: Option<f64>,
+    /// Inclusive upper bound
+    pub max: Option<f64>,
+}
+
+impl Expectation {
+    /// Creates an expectation that the statistic lies within the inclusive bounds.
+    pub fn new(statistic: Statistic, min: Option<f64>, max: Option<f64>) -> Self {
+        Self { statistic, min, max }
+    }
+
+    /// Returns `true` if the observed value is within the bounds, never for NaN.
+    pub fn allows(&self, observed: f64) -> bool {
+        !observed.is_nan()
+            && self.min.is_none_or(|min| observed >= min)
+            && self.max.is_none_or(|max| observed <= max)
+    }
+}
+
+/// Name of the temporary column holding the number of non-null values of a column.
+const COUNT: &str = "count";
+
+/// Computes the statistics of every expectation in a single aggregation and reports
+/// those outside their bounds as [`ValidationError::StatisticOutOfRange`].
+///
+/// Columns that are missing or have an unexpected dtype are skipped, they are reported
+/// by schema validation. A numeric statistic of a non-numeric column is reported as
+/// [`ValidationError::TypeMismatch`]. The null fraction of an empty frame and numeric
+/// statistics of empty or all-null columns are not checked; any other NaN statistic,
+/// e.g. the mean of a column containing NaN, is out of range.
+pub(crate) fn check_statistics(fields: &[FieldSpec], df: &DataFrame, report: &mut ValidationReport) {
+    let mut expectations = Vec::new();
+    for field in fields.iter().filter(|field| df.schema().get(field.name) == Some(&field.dtype)) {
+        for expectation in &field.expectations {
+            if expectation.statistic.is_numeric() && !field.dtype.is_primitive_numeric() {
+                report.push(ValidationError::TypeMismatch {
+                    column_name: field.name.to_string(),
+                    expected_type: format!("numeric dtype for {}", expectation.statistic),
+                    actual_type: format!("{:?}", field.dtype),
+                });
+            } else {
+                expectations.push((field.name, expectation));
+            }
+        }
+    }
+    if expectations.is_empty() {
+        return;
+    }
+
+    let exprs: Vec<_> = expectations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (column, expectation))| {
+            [
+                expectation.statistic.expr(column).alias(format!("statistic_{}", i)),
+                col(*column).count().alias(format!("{}_{}", COUNT, i)),
+            ]
+        })
+        .collect();
+    let observed = match df.clone().lazy().select(exprs).collect() {
+        Ok(observed) => observed,
+        Err(err) => return report.push(err.into()),
+    };
+
+    for ((column, expectation), values) in expectations.iter().zip(observed.get_columns().chunks(2)) {
+        let count = values[1].get(0).ok().and_then(|count| count.extract::<usize>()).unwrap_or(0);
+        let empty = match expectation.statistic {
+            Statistic::NullFraction => df.height() == 0,
+            Statistic::DistinctCount => false,
+            _ => count == 0,
+        };
+        if empty {
+            continue;
+        }
+        // The std of a single value is null, there is nothing to check
+        let Some(observed) = values[0].get(0).ok().and_then(|value| value.extract::<f64>()) else {
+            continue;
+        };
+        if !expectation.allows(observed) {
+            report.push(ValidationError::StatisticOutOfRange {
+                column_name: column.to_string(),
+                statistic: expectation.statistic.to_string(),
+                observed,
+                min: expectation.min,
+                max: expectation.max,
+            });
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use polars::prelude::*;
+
+use crate::error::ValidationError;
+use crate::keys::check_duplicates;
+use crate::report::ValidationReport;
+use crate::rows::{check_rows, format_value, CheckKind, RowCheck, MAX_SAMPLE_ROWS};
+
+/// Name of the temporary column holding the timestamp before a gap.
+const GAP_START: &str = "__polars_schema_gap_start";
+
+/// Name of the temporary column holding the length of a gap in nanoseconds.
+const GAP_LENGTH: &str = "__polars_schema_gap_length";
+
+/// Regular time grid a time-series DataFrame must follow, checked per entity.
+///
+/// Generated by `#[polars(timeseries(time = "ts", every = "1m", by = "sensor_id",
+/// max_gap = "5m"))]` on a struct deriving `PolarsSchema`. Durations are fixed lengths
+/// in Polars' duration syntax (`ns`, `us`, `ms`, `s`, `m`, `h`, `d`, `w`), calendar
+/// durations like `1mo` are not supported.
+///
+/// Validation reports:
+/// * timestamps occurring more than once per entity as [`ValidationError::DuplicateKeys`]
+/// * timestamps off the `every` grid, counted from the entity's first timestamp, as
+///   [`ValidationError::InvalidRows`]
+/// * intervals between consecutive timestamps of an entity longer than `max_gap` (or
+///   `every` if no `max_gap` is declared) as [`ValidationError::TimeGaps`]
+///
+/// Null timestamps are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    /// Date or datetime column holding the timestamps
+    pub time: &'static str,
+    /// Column identifying the entity, e.g. a sensor, `None` for a single series
+    pub by: Option<&'static str>,
+    /// Interval of the grid the timestamps must lie on
+    pub every: Option<Duration>,
+    /// Longest allowed interval between consecutive timestamps of an entity
+    pub max_gap: Option<Duration>,
+}
+
+/// Checks a DataFrame for duplicate timestamps, timestamps off the grid and gaps.
+///
+/// Missing columns are skipped, they are reported by schema validation.
+pub(crate) fn check_timeseries(timeseries: &TimeSeries, df: &DataFrame, report: &mut ValidationReport) {
+    let Some(dtype) = df.schema().get(timeseries.time) else {
+        return;
+    };
+    if timeseries.by.is_some_and(|by| !df.schema().contains(by)) {
+        return;
+    }
+    let unit_nanos: i64 = match dtype {
+        DataType::Date => 86_400_000_000_000,
+        DataType::Datetime(TimeUnit::Milliseconds, _) => 1_000_000,
+        DataType::Datetime(TimeUnit::Microseconds, _) => 1_000,
+        DataType::Datetime(TimeUnit::Nanoseconds, _) => 1,
+        _ => {
+            return report.push(ValidationError::TypeMismatch {
+                column_name: timeseries.time.to_string(),
+                expected_type: "Date or Datetime".to_string(),
+                actual_type: format!("{:?}", dtype),
+            });
+        }
+    };
+
+    let key: Vec<_> = timeseries.by.into_iter().chain([timeseries.time]).collect();
+    if let Err(err) = check_duplicates(df, &key, report) {
+        report.push(err.into());
+    }
+
+    let nanos = col(timeseries.time).to_physical().cast(DataType::Int64) * lit(unit_nanos);
+
+    if let Some(every) = timeseries.every {
+        let offset = nanos.clone() - over(nanos.clone().min(), timeseries.by);
+        let grid = RowCheck {
+            column_name: Some(timeseries.time.to_string()),
+            check: format!("every = \"{}\"", format_duration(every)),
+            kind: CheckKind::Values,
+            valid: (offset % lit(every.as_nanos() as i64)).eq(lit(0)),
+            value: Some(col(timeseries.time)),
+            tolerance: None,
+        };
+        check_rows(df, &[grid], report);
+    }
+
+    if let Some(max_gap) = timeseries.max_gap.or(timeseries.every) {
+        if let Err(err) = check_gaps(timeseries, nanos, max_gap, df, report) {
+            report.push(err.into());
+        }
+    }
+}
+
+/// Reports the intervals between consecutive timestamps of an entity longer than
+/// `max_gap`, in order of time.
+fn check_gaps(
+    timeseries: &TimeSeries,
+    nanos: Expr,
+    max_gap: Duration,
+    df: &DataFrame,
+    report: &mut ValidationReport,
+) -> PolarsResult<()> {
+    let time = timeseries.time;
+    let gap_length = nanos.clone() - over(nanos.shift(lit(1)), timeseries.by);
+
+    let mut columns: Vec<_> = timeseries.by.map(col).into_iter().collect();
+    columns.push(over(col(time).shift(lit(1)), timeseries.by).alias(GAP_START));
+    columns.push(col(time));
+    columns.push(gap_length.alias(GAP_LENGTH));
+
+    let gaps = df
+        .clone()
+        .lazy()
+        .filter(col(time).is_not_null())
+        .sort([time], SortMultipleOptions::default().with_maintain_order(true))
+        .select(columns)
+        .filter(col(GAP_LENGTH).gt(lit(max_gap.as_nanos() as i64)))
+        .collect()?;
+
+    if gaps.height() == 0 {
+        return Ok(());
+    }
+
+    let sample = (0..gaps.height().min(MAX_SAMPLE_ROWS))
+        .map(|row| {
+            let start = format_value(gaps.column(GAP_START)?.get(row)?);
+            let end = format_value(gaps.column(time)?.get(row)?);
+            Ok(match timeseries.by {
+                Some(by) => format!("{}: {} -> {}", format_value(gaps.column(by)?.get(row)?), start, end),
+                None => format!("{} -> {}", start, end),
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    report.push(ValidationError::TimeGaps {
+        column_name: time.to_string(),
+        max_gap: format_duration(max_gap),
+        gap_count: gaps.height(),
+        sample,
+    });
+    Ok(())
+}
+
+/// Evaluates the expression per entity if the time series has one.
+fn over(expr: Expr, by: Option<&str>) -> Expr {
+    match by {
+        Some(by) => expr.over([col(by)]),
+        None => expr,
+    }
+}
+
+/// Formats a duration in Polars' duration syntax, e.g. `1h30m`.
+fn format_duration(duration: Duration) -> String {
+    const UNITS: [(&str, u128); 8] = [
+        ("w", 604_800_000_000_000),
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("ns", 1),
+    ];
+
+    let mut nanos = duration.as_nanos();
+    let mut formatted = String::new();
+    for (unit, unit_nanos) in UNITS {
+        if nanos >= unit_nanos {
+            let _ = write!(formatted, "{}{}", nanos / unit_nanos, unit);
+            nanos %= unit_nanos;
+        }
+    }
+    if formatted.is_empty() {
+        formatted.push_str("0s");
+    }
+    formatted
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use polars::prelude::*;
+
+use crate::error::ValidationError;
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+use crate::{keys, rows, statistic, validator, PolarsSchema};
+
+/// Runs every check of `S` on a DataFrame, shared by `validate_all` and
+/// `validate_strict_all`; `strict` also reports unexpected columns.
+///
+/// The row count is checked last, after the errors of the columns.
+pub(crate) fn validate_frame<S: PolarsSchema + ?Sized>(df: &DataFrame, strict: bool) -> ValidationReport {
+    let fields = S::fields();
+    let mut report = ValidationReport::new();
+    check_columns(&fields, df.schema(), strict, &mut report);
+    rows::check_rows(df, &rows::row_checks(&fields, &S::checks(), df.schema()), &mut report);
+    statistic::check_statistics(&fields, df, &mut report);
+    keys::check_keys(&fields, S::primary_key().as_deref(), df, &mut report);
+    #[cfg(feature = "chrono")]
+    if let Some(timeseries) = S::timeseries() {
+        crate::timeseries::check_timeseries(&timeseries, df, &mut report);
+    }
+    validator::run_validators(&fields, &S::validators(), df, &mut report);
+    check_row_count(df.height(), S::row_bounds(), &mut report);
+    report
+}
+
+/// Checks that `schema` has exactly as many columns as expected (strict mode only).
+pub(crate) fn check_column_count(fields: &[FieldSpec], schema: &Schema) -> crate::Result<()> {
+    if schema.len() != fields.len() {
+        return Err(ValidationError::ColumnCountMismatch {
+            expected_count: fields.len(),
+            actual_count: schema.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that `row_count` is within the inclusive `(min, max)` bounds.
+pub(crate) fn check_row_count(
+    row_count: usize,
+    (min, max): (Option<usize>, Option<usize>),
+    report: &mut ValidationReport,
+) {
+    if min.is_some_and(|min| row_count < min) || max.is_some_and(|max| row_count > max) {
+        report.push(ValidationError::RowCount { row_count, min, max });
+    }
+}
+
+/// Checks the number of rows produced by a LazyFrame plan, if row bounds are declared.
+///
+/// Only a row count is selected, so projection pushdown avoids materializing columns.
+pub(crate) fn check_lazy_row_count(
+    lf: &LazyFrame,
+    bounds: (Option<usize>, Option<usize>),
+    report: &mut ValidationReport,
+) -> crate::Result<()> {
+    if bounds == (None, None) {
+        return Ok(());
+    }
+    let counts = lf.clone().select([len()]).collect()?;
+    let row_count = counts.get_columns()[0].get(0)?.extract::<usize>().unwrap_or(0);
+    check_row_count(row_count, bounds, report);
+    Ok(())
+}
+
+/// Checks that every expected column exists in `df_schema` with the expected type.
+///
+/// In strict mode, columns of `df_schema` that are not part of the expected schema
+/// are reported as [`ValidationError::UnexpectedColumn`].
+pub(crate) fn check_columns(
+    fields: &[FieldSpec],
+    df_schema: &Schema,
+    strict: bool,
+    report: &mut ValidationReport,
+) {
+    for FieldSpec { name, dtype: expected_type, .. } in fields {
+        match df_schema.get(name) {
+            None => report.push(ValidationError::MissingColumn {
+                column_name: name.to_string(),
+            }),
+            Some(actual_type) => check_dtype(name, expected_type, actual_type, strict, report),
+        }
+    }
+
+    if strict {
+        let expected_names: HashSet<_> = fields.iter().map(|field| field.name).collect();
+
+        for (col_name, _) in df_schema.iter() {
+            if !expected_names.contains(col_name.as_str()) {
+                report.push(ValidationError::UnexpectedColumn {
+                    column_name: col_name.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Compares an expected and an actual dtype, descending into struct fields and list items.
+///
+/// Mismatches inside nested structs are reported with dotted paths such as
+/// `address.zip_code`, mismatches of list and array items with paths such as `scores[]`.
+fn check_dtype(
+    path: &str,
+    expected: &DataType,
+    actual: &DataType,
+    strict: bool,
+    report: &mut ValidationReport,
+) {
+    match (expected, actual) {
+        (DataType::Struct(expected_fields), DataType::Struct(actual_fields)) => {
+            for expected_field in expected_fields {
+                let field_path = format!("{}.{}", path, expected_field.name());
+                match actual_fields.iter().find(|f| f.name() == expected_field.name()) {
+                    None => report.push(ValidationError::MissingColumn {
+                        column_name: field_path,
+                    }),
+                    Some(actual_field) => check_dtype(
+                        &field_path,
+                        expected_field.dtype(),
+                        actual_field.dtype(),
+                        strict,
+                        report,
+                    ),
+                }
+            }
+
+            if strict {
+                for actual_field in actual_fields {
+                    if !expected_fields.iter().any(|f| f.name() == actual_field.name()) {
+                        report.push(ValidationError::UnexpectedColumn {
+                            column_name: format!("{}.{}", path, actual_field.name()),
+                        });
+                    }
+                }
+            }
+        }
+        (DataType::List(expected_inner), DataType::List(actual_inner)) => {
+            check_dtype(&format!("{}[]", path), expected_inner, actual_inner, strict, report);
+        }
+        (DataType::Array(expected_inner, expected_width), DataType::Array(actual_inner, actual_width))
+            if expected_width == actual_width =>
+        {
+            check_dtype(&format!("{}[]", path), expected_inner, actual_inner, strict, report);
+        }
+        _ => {
+            if actual != expected {
+                report.push(ValidationError::TypeMismatch {
+                    column_name: path.to_string(),
+                    expected_type: format!("{:?}", expected),
+                    actual_type: format!("{:?}", actual),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use polars::prelude::*;
+
+use crate::error::Result;
+use crate::field::FieldSpec;
+use crate::report::ValidationReport;
+
+/// A custom Rust validation function, for rules that are easier to write in Rust than
+/// as Polars expressions.
+///
+/// Field validators receive the column as a [`Series`], struct validators the whole
+/// [`DataFrame`]. Generated by `#[polars(validate_with = "path::to::fn")]`:
+///
+/// ```rust
+/// use polars::prelude::*;
+/// use polars_schema_validate::{PolarsSchema, Result, ValidationError};
+///
+/// fn even(series: &Series) -> Result<()> {
+///     let odd = series.i64()?.into_iter().flatten().filter(|v| v % 2 != 0).count();
+///     if odd == 0 {
+///         return Ok(());
+///     }
+///     Err(ValidationError::InvalidRows {
+///         column_name: series.name().to_string(),
+///         check: "even".to_string(),
+///         failing_count: odd,
+///         sample: Vec::new(),
+///     })
+/// }
+///
+/// #[derive(PolarsSchema)]
+/// struct Pair {
+///     #[polars(validate_with = "even")]
+///     value: i64,
+/// }
+///
+/// let df = df!["value" => [2i64, 3]].unwrap();
+/// assert!(Pair::validate(&df).is_err());
+/// ```
+pub struct Validator<T: ?Sized> {
+    /// Path of the function as written in the attribute
+    pub name: &'static str,
+    /// The validation function
+    pub func: fn(&T) -> Result<()>,
+}
+
+impl<T: ?Sized> Validator<T> {
+    /// Creates a validator with the given name and function.
+    pub fn new(name: &'static str, func: fn(&T) -> Result<()>) -> Self {
+        Self { name, func }
+    }
+}
+
+impl<T: ?Sized> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Validator<T> {}
+
+// Validators are compared by name, comparing function pointers is unreliable
+impl<T: ?Sized> PartialEq for Validator<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Validator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validator").field(&self.name).finish()
+    }
+}
+
+/// Runs field validators on their columns, then struct validators on the DataFrame.
+///
+/// Field validators are skipped for columns that are missing or have an unexpected
+/// dtype, those are reported by schema validation.
+pub(crate) fn run_validators(
+    fields: &[FieldSpec],
+    struct_validators: &[Validator<DataFrame>],
+    df: &DataFrame,
+    report: &mut ValidationReport,
+) {
+    for field in fields {
+        let Ok(column) = df.column(field.name) else {
+            continue;
+        };
+        if column.dtype() != &field.dtype {
+            continue;
+        }
+        for validator in &field.validators {
+            if let Err(err) = (validator.func)(column.as_materialized_series()) {
+                report.push(err);
+            }
+        }
+    }
+
+    for validator in struct_validators {
+        if let Err(err) = (validator.func)(df) {
+            report.push(err);
+        }
+    }
+}
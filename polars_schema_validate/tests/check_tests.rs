@@ -0,0 +1,120 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError, VIOLATIONS_COLUMN};
+
+mod rules {
+    use polars::prelude::*;
+
+    pub fn discount_below_price() -> Expr {
+        col("discount").lt(col("price"))
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(check = "col(\"start\").lt_eq(col(\"end\"))", name = "start_before_end")]
+#[polars(check = rules::discount_below_price)]
+#[allow(dead_code)]
+struct Promotion {
+    start: i32,
+    end: i32,
+    price: f64,
+    discount: Option<f64>,
+}
+
+fn promotion_frame(ends: &[i32], discounts: &[Option<f64>]) -> DataFrame {
+    df![
+        "start" => vec![5; ends.len()],
+        "end" => ends,
+        "price" => vec![10.0; ends.len()],
+        "discount" => discounts,
+    ].unwrap()
+}
+
+#[test]
+fn test_check_declarations() {
+    let names: Vec<_> = Promotion::checks().iter().map(|check| check.name).collect();
+    assert_eq!(names, vec!["start_before_end", "rules::discount_below_price"]);
+}
+
+#[test]
+fn test_checks_pass() {
+    let df = promotion_frame(&[5, 9], &[Some(1.0), None]);
+    let report = Promotion::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_failing_checks_reported() {
+    let df = promotion_frame(&[4, 9, 1, 6], &[Some(1.0), Some(10.0), None, Some(12.5)]);
+
+    let report = Promotion::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::FailedCheck {
+                check: "start_before_end".to_string(),
+                failing_count: 2,
+                rows: vec![0, 2],
+            },
+            ValidationError::FailedCheck {
+                check: "rules::discount_below_price".to_string(),
+                failing_count: 2,
+                rows: vec![1, 3],
+            },
+        ]
+    );
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Check 'start_before_end' failed in 2 row(s) [rows 0, 2]"
+    );
+}
+
+#[test]
+fn test_checks_skipped_for_missing_columns() {
+    let df = df![
+        "start" => [5],
+        "price" => [10.0],
+        "discount" => [20.0],
+    ].unwrap();
+
+    let report = Promotion::validate_all(&df);
+    assert_eq!(report.len(), 2);
+    assert_eq!(report.errors()[0], ValidationError::MissingColumn { column_name: "end".to_string() });
+    assert!(matches!(&report.errors()[1], ValidationError::FailedCheck { check, .. } if check == "rules::discount_below_price"));
+}
+
+#[test]
+fn test_partition_lists_failed_checks() {
+    let df = promotion_frame(&[4, 9], &[Some(20.0), None]);
+
+    let (valid, quarantine) = Promotion::partition(&df).unwrap();
+    assert_eq!(valid.height(), 1);
+    let violations = quarantine.column(VIOLATIONS_COLUMN).unwrap().list().unwrap().get_as_series(0).unwrap();
+    let violations: Vec<_> = violations.str().unwrap().into_no_null_iter().collect();
+    assert_eq!(violations, vec!["start_before_end", "rules::discount_below_price"]);
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(check = "col(\"code\").str().starts_with(lit(\"P\"))", name = "code_prefix")]
+#[allow(dead_code)]
+struct Coupon {
+    code: String,
+    #[polars(min = 0)]
+    amount: i64,
+}
+
+#[test]
+fn test_unevaluable_check_only_fails_itself() {
+    let df = df![
+        "code" => [1i64, 2],
+        "amount" => [Some(5i64), None],
+    ].unwrap();
+
+    let report = Coupon::validate_all(&df);
+    assert_eq!(report.len(), 3, "{}", report);
+    assert!(matches!(&report.errors()[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "code"));
+    assert_eq!(
+        report.errors()[1],
+        ValidationError::NullValues { column_name: "amount".to_string(), null_count: 1, rows: vec![1] }
+    );
+    assert!(matches!(&report.errors()[2], ValidationError::Polars { message } if message.starts_with("code_prefix: ")));
+}
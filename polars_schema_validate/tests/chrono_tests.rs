@@ -1,3 +1,5 @@
+#![cfg(feature = "chrono")]
+
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use polars::prelude::*;
 use polars_schema_validate::PolarsSchema;
@@ -97,4 +99,21 @@ fn test_chrono_type_mismatch() {
     let result = EventRecord::validate(&df);
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("type"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_qualified_chrono_path() {
+    #[derive(PolarsSchema)]
+    #[allow(dead_code)]
+    struct Invoice {
+        issued_on: ::chrono::NaiveDate,
+    }
+
+    assert_eq!(Invoice::schema(), vec![("issued_on", DataType::Date)]);
+    let df = df!["issued_on" => [0i32, 1i32]].unwrap()
+        .lazy()
+        .with_column(col("issued_on").cast(DataType::Date))
+        .collect()
+        .unwrap();
+    assert!(Invoice::validate(&df).is_ok());
+}
@@ -0,0 +1,117 @@
+use polars::prelude::*;
+use polars_schema_validate::{CastMode, PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Reading {
+    sensor: String,
+    value: f64,
+    count: i32,
+    note: Option<String>,
+}
+
+#[test]
+fn test_conform_reorders_casts_and_drops() {
+    let df = df![
+        "extra" => [true, false],
+        "count" => [1i64, 2i64],
+        "value" => [1i32, 2i32],
+        "sensor" => ["a", "b"],
+    ].unwrap();
+
+    let conformed = Reading::conform(&df).unwrap();
+    assert_eq!(
+        conformed.get_column_names_str(),
+        vec!["sensor", "value", "count", "note"]
+    );
+    assert!(Reading::validate_strict(&conformed).is_ok());
+    assert_eq!(conformed.column("note").unwrap().null_count(), 2);
+}
+
+#[test]
+fn test_conform_missing_required_column() {
+    let df = df!["sensor" => ["a"], "value" => [1.0]].unwrap();
+
+    let error = Reading::conform(&df).unwrap_err();
+    assert!(matches!(error, ValidationError::MissingColumn { ref column_name } if column_name == "count"));
+}
+
+#[test]
+fn test_conform_strict_rejects_lossy_casts() {
+    let df = df![
+        "sensor" => ["a", "b", "c"],
+        "value" => [1.0, 2.0, 3.0],
+        "count" => [1.0, 2.5, 3.7],
+    ].unwrap();
+
+    let error = Reading::conform(&df).unwrap_err();
+    match error {
+        ValidationError::LossyCast { column_name, lossy_count, .. } => {
+            assert_eq!(column_name, "count");
+            assert_eq!(lossy_count, 2);
+        }
+        _ => panic!("Expected LossyCast error, got: {:?}", error),
+    }
+
+    // Lenient casting truncates instead
+    let conformed = Reading::conform_with(&df, CastMode::Lenient).unwrap();
+    assert_eq!(conformed.column("count").unwrap().dtype(), &DataType::Int32);
+}
+
+#[test]
+fn test_conform_strict_rejects_unparseable_strings() {
+    let df = df![
+        "sensor" => ["a", "b"],
+        "value" => ["1.5", "oops"],
+        "count" => [1i32, 2i32],
+    ].unwrap();
+
+    let error = Reading::conform(&df).unwrap_err();
+    assert!(matches!(error, ValidationError::LossyCast { ref column_name, lossy_count: 1, .. } if column_name == "value"));
+
+    let conformed = Reading::conform_with(&df, CastMode::Lenient).unwrap();
+    assert_eq!(conformed.column("value").unwrap().null_count(), 1);
+}
+
+#[test]
+fn test_conform_lazy() {
+    let lf = df![
+        "count" => [1i64, 2i64],
+        "value" => [1i32, 2i32],
+        "sensor" => ["a", "b"],
+        "extra" => [true, false],
+    ].unwrap()
+    .lazy();
+
+    let mut conformed = Reading::conform_lazy(lf).unwrap();
+    assert!(Reading::validate_lazy_strict(&mut conformed).is_ok());
+    let df = conformed.collect().unwrap();
+    assert!(Reading::validate_strict(&df).is_ok());
+
+    let lf = df!["sensor" => ["a"]].unwrap().lazy();
+    assert!(matches!(
+        Reading::conform_lazy(lf),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
+
+#[test]
+fn test_conform_lazy_rejects_lossy_casts() {
+    let lf = df![
+        "sensor" => ["a", "b", "c"],
+        "value" => [1.0, 2.0, 3.0],
+        "count" => [1.0, 2.5, 3.7],
+    ].unwrap()
+    .lazy();
+
+    // The plan builds, the lossy cast only fails once the data is read
+    let error = Reading::conform_lazy(lf.clone()).unwrap().collect().unwrap_err();
+    assert!(
+        error.to_string().contains("Casting column 'count' from Float64 to Int32 would lose 2 value(s)"),
+        "{}",
+        error
+    );
+
+    let conformed = Reading::conform_lazy_with(lf, CastMode::Lenient).unwrap().collect().unwrap();
+    assert_eq!(conformed.column("count").unwrap().dtype(), &DataType::Int32);
+}
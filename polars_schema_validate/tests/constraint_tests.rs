@@ -0,0 +1,183 @@
+use polars::prelude::*;
+use polars_schema_validate::{Constraint, ConstraintValue, PolarsDtype, PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Person {
+    #[polars(len(min = 1, max = 8))]
+    id: String,
+    #[polars(min = 0, max = 150)]
+    age: i32,
+    #[polars(min = -10.5)]
+    balance: f64,
+    #[polars(regex = "^[A-Z]{2}$")]
+    country: Option<String>,
+    #[polars(one_of("admin", "user"))]
+    role: String,
+    #[polars(len(max = 2))]
+    tags: Vec<String>,
+}
+
+fn person_frame(ids: &[&str], ages: &[i32], countries: &[Option<&str>], roles: &[&str]) -> DataFrame {
+    let n = ids.len();
+    let tags = Series::new(
+        "tags".into(),
+        vec![Series::new("".into(), ["a"]); n],
+    );
+    let mut df = df![
+        "id" => ids,
+        "age" => ages,
+        "balance" => vec![0.0; n],
+        "country" => countries,
+        "role" => roles,
+    ].unwrap();
+    df.with_column(tags).unwrap();
+    df
+}
+
+#[test]
+fn test_constraints_from_attributes() {
+    let fields = Person::fields();
+    assert_eq!(fields[0].constraints, vec![Constraint::Length { min: Some(1), max: Some(8) }]);
+    assert_eq!(
+        fields[1].constraints,
+        vec![
+            Constraint::Min(ConstraintValue::Int(0)),
+            Constraint::Max(ConstraintValue::Int(150)),
+        ]
+    );
+    assert_eq!(fields[2].constraints, vec![Constraint::Min(ConstraintValue::Float(-10.5))]);
+    assert_eq!(fields[3].constraints, vec![Constraint::Regex("^[A-Z]{2}$")]);
+    assert_eq!(
+        fields[4].constraints,
+        vec![Constraint::OneOf(vec![ConstraintValue::Str("admin"), ConstraintValue::Str("user")])]
+    );
+    assert_eq!(fields[1].constraints[1].to_string(), "max = 150");
+    assert_eq!(fields[0].constraints[0].to_string(), "len(min = 1, max = 8)");
+}
+
+#[test]
+fn test_valid_values_pass() {
+    let df = person_frame(
+        &["a1", "b2"],
+        &[30, 0],
+        &[Some("US"), None],
+        &["admin", "user"],
+    );
+    let report = Person::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_constraint_violations_reported() {
+    let df = person_frame(
+        &["", "b2", "much-too-long"],
+        &[-1, 200, 30],
+        &[Some("usa"), Some("DE"), Some("X")],
+        &["admin", "root", "guest"],
+    );
+
+    let report = Person::validate_all(&df);
+    let violations: Vec<_> = report
+        .iter()
+        .map(|e| match e {
+            ValidationError::ConstraintViolation { column_name, constraint, failing_count, .. } => {
+                (column_name.as_str(), constraint.as_str(), *failing_count)
+            }
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect();
+
+    assert_eq!(
+        violations,
+        vec![
+            ("id", "len(min = 1, max = 8)", 2),
+            ("age", "min = 0", 1),
+            ("age", "max = 150", 1),
+            ("country", "regex = \"^[A-Z]{2}$\"", 2),
+            ("role", "one_of(\"admin\", \"user\")", 2),
+        ]
+    );
+    assert_eq!(
+        report.errors()[1].to_string(),
+        "Column 'age' violates constraint min = 0 in 1 row(s) [row 0: -1]"
+    );
+}
+
+#[test]
+fn test_list_length_constraint() {
+    let mut df = person_frame(&["a"], &[1], &[None], &["user"]);
+    let tags = Series::new(
+        "tags".into(),
+        [Series::new("".into(), ["a", "b", "c"])],
+    );
+    df.with_column(tags).unwrap();
+
+    let error = Person::validate(&df).unwrap_err();
+    assert!(matches!(error, ValidationError::ConstraintViolation { ref column_name, .. } if column_name == "tags"));
+}
+
+#[test]
+fn test_failing_rows_sample_is_capped() {
+    let ids: Vec<_> = (0..25).map(|i| format!("id{}", i)).collect();
+    let ids: Vec<_> = ids.iter().map(String::as_str).collect();
+    let ages: Vec<_> = (0..25).map(|i| -i - 1).collect();
+    let df = person_frame(&ids, &ages, &vec![None; 25], &vec!["user"; 25]);
+
+    let error = Person::validate(&df).unwrap_err();
+    let ValidationError::ConstraintViolation { failing_count, sample, .. } = &error else {
+        panic!("Unexpected error: {:?}", error);
+    };
+    assert_eq!(*failing_count, 25);
+    assert_eq!(sample.len(), 10);
+    assert_eq!(sample[0], (0, "-1".to_string()));
+    assert_eq!(sample[9], (9, "-10".to_string()));
+    assert!(error.to_string().ends_with("row 9: -10, ...]"));
+}
+
+#[test]
+fn test_unevaluable_constraint_only_fails_itself() {
+    // Checked against the dtype at runtime only, as the derive cannot see through it
+    struct Code;
+
+    impl PolarsDtype for Code {
+        fn dtype() -> DataType {
+            DataType::String
+        }
+    }
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code)]
+    struct Product {
+        #[polars(min = 0)]
+        code: Code,
+        #[polars(min = 0)]
+        stock: i32,
+    }
+
+    let df = df![
+        "code" => ["a", "b"],
+        "stock" => [Some(-1), None],
+    ].unwrap();
+
+    let report = Product::validate_all(&df);
+    assert_eq!(report.len(), 3);
+    assert!(matches!(&report.errors()[0], ValidationError::Polars { message } if message.starts_with("code: min = 0: ")));
+    assert!(matches!(&report.errors()[1], ValidationError::NullValues { column_name, .. } if column_name == "stock"));
+    assert!(matches!(&report.errors()[2], ValidationError::ConstraintViolation { column_name, .. } if column_name == "stock"));
+}
+
+#[test]
+fn test_one_of_numbers() {
+    #[derive(PolarsSchema)]
+    #[allow(dead_code)]
+    struct Review {
+        // Integers and floats are both numbers
+        #[polars(one_of(1, 2.5))]
+        rating: f64,
+    }
+
+    let report = Review::validate_all(&df!["rating" => [1.0, 2.5, 3.0]].unwrap());
+    assert_eq!(report.len(), 1);
+    assert!(matches!(&report.errors()[0], ValidationError::ConstraintViolation { failing_count: 1, .. }));
+}
@@ -0,0 +1,136 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsDtype, PolarsSchema};
+
+type Amount = f64;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct CustomerId(i64);
+
+impl PolarsDtype for CustomerId {
+    fn dtype() -> DataType {
+        DataType::Int64
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Invoice {
+    customer_id: CustomerId,
+    backup_customer_id: Option<CustomerId>,
+    label: std::string::String,
+    amount: Amount,
+}
+
+#[test]
+fn test_types_resolve_through_trait() {
+    let schema = Invoice::schema();
+    assert_eq!(
+        schema,
+        vec![
+            ("customer_id", DataType::Int64),
+            ("backup_customer_id", DataType::Int64),
+            ("label", DataType::String),
+            ("amount", DataType::Float64),
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn test_option_of_custom_type_is_nullable() {
+    assert!(!<CustomerId as PolarsDtype>::NULLABLE);
+    assert!(<Option<CustomerId> as PolarsDtype>::NULLABLE);
+
+    let nullable: Vec<_> = Invoice::fields().iter().map(|f| f.nullable).collect();
+    assert_eq!(nullable, vec![false, true, false, false]);
+}
+
+#[test]
+fn test_validate_with_custom_type() {
+    let df = df![
+        "customer_id" => [1i64, 2i64],
+        "backup_customer_id" => [None, Some(7i64)],
+        "label" => ["a", "b"],
+        "amount" => [1.0, 2.0],
+    ].unwrap();
+
+    assert!(Invoice::validate(&df).is_ok());
+}
+
+#[test]
+fn test_generic_struct_derive() {
+    #[derive(PolarsSchema)]
+    #[allow(dead_code)]
+    struct Tagged<'a> {
+        tag: &'a str,
+        weight: f32,
+    }
+
+    assert_eq!(
+        Tagged::schema(),
+        vec![("tag", DataType::String), ("weight", DataType::Float32)]
+    );
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Overrides {
+    #[polars(dtype = "Categorical")]
+    category: String,
+    #[polars(dtype = "Datetime(ms, UTC)")]
+    created_at: i64,
+    #[polars(dtype = "Duration(ns)")]
+    elapsed: Option<i64>,
+    #[polars(dtype = "List(Int32)")]
+    values: String,
+}
+
+#[test]
+fn test_dtype_override() {
+    let schema = Overrides::schema();
+    assert!(matches!(schema[0].1, DataType::Categorical(_, _)));
+    assert_eq!(schema[1].1, DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC)));
+    assert_eq!(schema[2].1, DataType::Duration(TimeUnit::Nanoseconds));
+    assert_eq!(schema[3].1, DataType::List(Box::new(DataType::Int32)));
+
+    // Nullability still follows the Rust type
+    assert!(Overrides::fields()[2].nullable);
+    assert!(!Overrides::fields()[1].nullable);
+}
+
+#[test]
+fn test_dtype_override_time_zones() {
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    struct Zoned {
+        #[polars(dtype = "Datetime(ms, Europe/Paris)")]
+        local: i64,
+        #[polars(dtype = "Datetime(us, +01:00)")]
+        offset: i64,
+    }
+
+    let schema = Zoned::schema();
+    assert!(matches!(&schema[0].1, DataType::Datetime(TimeUnit::Milliseconds, Some(tz)) if tz.as_str() == "Europe/Paris"));
+    assert!(matches!(&schema[1].1, DataType::Datetime(TimeUnit::Microseconds, Some(_))));
+}
+
+#[test]
+fn test_validate_categorical_override() {
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    struct Labelled {
+        #[polars(dtype = "Categorical")]
+        label: String,
+    }
+
+    let df = df!["label" => ["a", "b", "a"]].unwrap();
+    assert!(Labelled::validate(&df).is_err());
+
+    let df = df
+        .lazy()
+        .with_column(col("label").cast(DataType::Categorical(None, Default::default())))
+        .collect()
+        .unwrap();
+    assert!(Labelled::validate(&df).is_ok());
+}
@@ -0,0 +1,155 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsEnum, PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsEnum)]
+#[polars(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum Status {
+    Pending,
+    Shipped,
+    Cancelled,
+    #[polars(rename = "on hold")]
+    OnHold,
+}
+
+#[derive(Debug, PolarsEnum)]
+#[polars(categorical, rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code)]
+enum Priority {
+    Low,
+    VeryHigh,
+}
+
+#[derive(Debug, PolarsEnum)]
+#[polars(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum LowerState {
+    OnHold,
+    InTransit,
+}
+
+#[derive(Debug, PolarsEnum)]
+#[polars(rename_all = "UPPERCASE")]
+#[allow(dead_code)]
+enum UpperState {
+    OnHold,
+    InTransit,
+}
+
+#[derive(Debug, PolarsEnum)]
+#[polars(rename_all = "camelCase")]
+#[allow(dead_code)]
+enum CamelState {
+    OnHold,
+    InTransit,
+}
+
+#[derive(Debug, PolarsEnum)]
+#[polars(rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum KebabState {
+    OnHold,
+    InTransit,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Order {
+    id: i64,
+    status: Status,
+    priority: Option<Priority>,
+    #[polars(dtype = "String")]
+    previous_status: Status,
+}
+
+#[test]
+fn test_enum_variants() {
+    assert_eq!(Status::VARIANTS, &["pending", "shipped", "cancelled", "on hold"]);
+    assert_eq!(Priority::VARIANTS, &["LOW", "VERY_HIGH"]);
+    assert_eq!(Status::OnHold.as_str(), "on hold");
+}
+
+#[test]
+fn test_enum_rename_all_multi_word_variants() {
+    assert_eq!(LowerState::VARIANTS, &["onhold", "intransit"]);
+    assert_eq!(UpperState::VARIANTS, &["ONHOLD", "INTRANSIT"]);
+    assert_eq!(CamelState::VARIANTS, &["onHold", "inTransit"]);
+    assert_eq!(KebabState::VARIANTS, &["on-hold", "in-transit"]);
+}
+
+#[test]
+fn test_enum_dtypes() {
+    let schema = Order::schema();
+    assert!(matches!(schema[1].1, DataType::Enum(_, _)));
+    assert!(matches!(schema[2].1, DataType::Categorical(_, _)));
+    assert_eq!(schema[3].1, DataType::String);
+
+    let fields = Order::fields();
+    assert_eq!(fields[1].categories, Some(vec!["pending", "shipped", "cancelled", "on hold"]));
+    assert_eq!(fields[2].categories, Some(vec!["LOW", "VERY_HIGH"]));
+    assert!(fields[2].nullable);
+}
+
+fn order_frame(priority: &[Option<&str>], previous_status: &[&str]) -> DataFrame {
+    df![
+        "id" => [1i64, 2i64, 3i64],
+        "status" => ["pending", "shipped", "on hold"],
+        "priority" => priority,
+        "previous_status" => previous_status,
+    ].unwrap()
+    .lazy()
+    .with_columns([
+        col("status").cast(<Status as polars_schema_validate::PolarsDtype>::dtype()),
+        col("priority").cast(DataType::Categorical(None, Default::default())),
+    ])
+    .collect()
+    .unwrap()
+}
+
+#[test]
+fn test_enum_valid_values() {
+    let df = order_frame(&[Some("LOW"), None, Some("VERY_HIGH")], &["pending", "pending", "shipped"]);
+    let report = Order::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_enum_invalid_values_reported() {
+    let df = order_frame(&[Some("LOW"), Some("urgent"), Some("urgent")], &["pending", "lost", "unknown"]);
+
+    let report = Order::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::InvalidCategory {
+                column_name: "priority".to_string(),
+                invalid_count: 2,
+                values: vec!["urgent".to_string()],
+                rows: vec![1, 2],
+            },
+            ValidationError::InvalidCategory {
+                column_name: "previous_status".to_string(),
+                invalid_count: 2,
+                values: vec!["lost".to_string(), "unknown".to_string()],
+                rows: vec![1, 2],
+            },
+        ]
+    );
+    assert_eq!(
+        report.errors()[1].to_string(),
+        "Column 'previous_status' contains 2 value(s) that are not valid categories: 'lost', 'unknown' [rows 1, 2]"
+    );
+}
+
+#[test]
+fn test_enum_column_with_string_dtype_is_type_mismatch() {
+    let df = df![
+        "id" => [1i64],
+        "status" => ["pending"],
+        "priority" => ["LOW"],
+        "previous_status" => ["pending"],
+    ].unwrap();
+
+    let report = Order::validate_all(&df);
+    assert!(matches!(&report.errors()[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "status"));
+}
@@ -0,0 +1,126 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Measurement {
+    ratio: f64,
+    score: Option<f32>,
+    #[polars(allow_nan, allow_inf)]
+    raw: f64,
+    #[polars(allow_inf)]
+    limit: f64,
+}
+
+fn failing(report: &polars_schema_validate::ValidationReport) -> Vec<(&str, &str, usize)> {
+    report
+        .iter()
+        .map(|e| match e {
+            ValidationError::InvalidRows { column_name, check, failing_count, .. } => {
+                (column_name.as_str(), check.as_str(), *failing_count)
+            }
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect()
+}
+
+#[test]
+fn test_float_flags() {
+    let fields = Measurement::fields();
+    let flags: Vec<_> = fields.iter().map(|f| (f.name, f.allow_nan, f.allow_inf)).collect();
+    assert_eq!(
+        flags,
+        vec![
+            ("ratio", false, false),
+            ("score", false, false),
+            ("raw", true, true),
+            ("limit", false, true),
+        ]
+    );
+}
+
+#[test]
+fn test_finite_floats_pass() {
+    let df = df![
+        "ratio" => [0.5, 1.5],
+        "score" => [Some(1.0f32), None],
+        "raw" => [f64::NAN, f64::INFINITY],
+        "limit" => [f64::NEG_INFINITY, 1.0],
+    ].unwrap();
+
+    let report = Measurement::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_nan_and_infinity_rejected() {
+    let df = df![
+        "ratio" => [f64::NAN, 1.0, f64::INFINITY, f64::NEG_INFINITY],
+        "score" => [Some(f32::NAN), None, Some(1.0), Some(2.0)],
+        "raw" => [0.0, 0.0, 0.0, 0.0],
+        "limit" => [f64::NAN, f64::INFINITY, 0.0, 0.0],
+    ].unwrap();
+
+    let report = Measurement::validate_all(&df);
+    assert_eq!(
+        failing(&report),
+        vec![
+            ("ratio", "not_nan", 1),
+            ("ratio", "finite", 2),
+            ("score", "not_nan", 1),
+            ("limit", "not_nan", 1),
+        ]
+    );
+    let ValidationError::InvalidRows { sample, .. } = &report.errors()[1] else {
+        unreachable!();
+    };
+    let rows: Vec<_> = sample.iter().map(|(row, _)| *row).collect();
+    assert_eq!(rows, vec![2, 3]);
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Bounds {
+    low: f64,
+    #[polars(allow_nan)]
+    high: f64,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Envelope {
+    bounds: Bounds,
+    values: Vec<f64>,
+    #[polars(allow_nan, allow_inf)]
+    raw_values: Vec<f64>,
+}
+
+#[test]
+fn test_nested_and_list_floats() {
+    let values = || [Series::new("".into(), [1.0, f64::NAN]), Series::new("".into(), [f64::INFINITY])];
+    let df = df![
+        "low" => [f64::NAN, 0.0],
+        "high" => [f64::NAN, 1.0],
+        "values" => values(),
+        "raw_values" => values(),
+    ]
+    .unwrap()
+    .lazy()
+    .select([
+        as_struct(vec![col("low"), col("high")]).alias("bounds"),
+        col("values"),
+        col("raw_values"),
+    ])
+    .collect()
+    .unwrap();
+
+    let report = Envelope::validate_all(&df);
+    assert_eq!(
+        failing(&report),
+        vec![
+            ("bounds.low", "not_nan", 1),
+            ("values[]", "not_nan", 1),
+            ("values[]", "finite", 1),
+        ]
+    );
+}
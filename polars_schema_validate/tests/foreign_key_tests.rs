@@ -0,0 +1,164 @@
+use polars::prelude::*;
+use polars_schema_validate::{check_foreign_keys, ForeignKey, PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Customer {
+    #[polars(rename = "customer_key")]
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Product {
+    sku: String,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Order {
+    order_id: i64,
+    #[polars(foreign_key = "Customer::customer_key")]
+    customer_id: Option<i64>,
+    #[polars(foreign_key = "Product::sku")]
+    sku: String,
+}
+
+fn customers() -> DataFrame {
+    df![
+        "customer_key" => [1i64, 2, 3],
+        "name" => ["Alice", "Bob", "Charlie"],
+    ].unwrap()
+}
+
+#[test]
+fn test_foreign_key_declaration() {
+    let fields = Order::fields();
+    assert_eq!(fields[0].foreign_key, None);
+    let foreign_key = fields[1].foreign_key.as_ref().unwrap();
+    assert_eq!(foreign_key.table, "Customer");
+    assert_eq!(foreign_key.column, "customer_key");
+    assert_eq!(foreign_key.type_id, std::any::TypeId::of::<Customer>());
+    assert!(matches!(fields[2].foreign_key, Some(ForeignKey { table: "Product", column: "sku", .. })));
+}
+
+#[test]
+fn test_foreign_keys_present() {
+    let orders = df![
+        "order_id" => [10i64, 11, 12],
+        "customer_id" => [Some(1i64), None, Some(3)],
+        "sku" => ["x", "y", "z"],
+    ].unwrap();
+
+    let report = check_foreign_keys::<Order, Customer>(&orders, &customers());
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_orphaned_keys_reported() {
+    let orders = df![
+        "order_id" => [10i64, 11, 12, 13, 14],
+        "customer_id" => [Some(7i64), Some(1), Some(9), Some(7), None],
+        "sku" => ["x", "y", "z", "x", "y"],
+    ].unwrap();
+
+    let report = check_foreign_keys::<Order, Customer>(&orders, &customers());
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::OrphanedKeys {
+            column_name: "customer_id".to_string(),
+            referenced: "Customer.customer_key".to_string(),
+            orphan_count: 3,
+            sample: vec!["7".to_string(), "9".to_string()],
+        }]
+    );
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Column 'customer_id' has 3 row(s) with keys not found in Customer.customer_key [7, 9]"
+    );
+
+    // Only the foreign keys referencing the given schema are checked
+    let products = df!["sku" => ["x", "y"]].unwrap();
+    let report = check_foreign_keys::<Order, Product>(&orders, &products);
+    assert_eq!(report.len(), 1);
+    assert!(matches!(&report.errors()[0], ValidationError::OrphanedKeys { column_name, orphan_count: 1, .. } if column_name == "sku"));
+}
+
+#[test]
+fn test_foreign_key_columns_must_exist_and_match() {
+    let orders = df![
+        "order_id" => [10i64],
+        "sku" => ["x"],
+    ].unwrap();
+    let report = check_foreign_keys::<Order, Customer>(&orders, &customers());
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::MissingColumn { column_name: "customer_id".to_string() }]
+    );
+
+    let orders = df![
+        "order_id" => [10i64],
+        "customer_id" => [1i32],
+        "sku" => ["x"],
+    ].unwrap();
+    let report = check_foreign_keys::<Order, Customer>(&orders, &customers());
+    assert!(matches!(&report.errors()[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "customer_id"));
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Invoice {
+    #[polars(foreign_key = "Customer::id")]
+    customer_id: i64,
+}
+
+#[test]
+fn test_referenced_column_must_exist() {
+    // `id` is renamed to `customer_key` in `Customer`
+    let invoices = df!["customer_id" => [1i64]].unwrap();
+    let report = check_foreign_keys::<Invoice, Customer>(&invoices, &customers());
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::MissingColumn { column_name: "Customer.id".to_string() }]
+    );
+
+    let orders = df![
+        "order_id" => [10i64],
+        "customer_id" => [1i64],
+        "sku" => ["x"],
+    ].unwrap();
+    let report = check_foreign_keys::<Order, Customer>(&orders, &df!["name" => ["Alice"]].unwrap());
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::MissingColumn { column_name: "Customer.customer_key".to_string() }]
+    );
+}
+
+mod billing {
+    use polars_schema_validate::PolarsSchema;
+
+    /// Same name as the top-level schema, only matched by type
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    pub struct Customer {
+        pub id: i64,
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Payment {
+    #[polars(foreign_key = "billing::Customer::id")]
+    customer_id: i64,
+}
+
+#[test]
+fn test_foreign_keys_matched_by_type() {
+    let payments = df!["customer_id" => [1i64, 5]].unwrap();
+    let report = check_foreign_keys::<Payment, Customer>(&payments, &customers());
+    assert!(report.is_valid(), "{}", report);
+
+    let report = check_foreign_keys::<Payment, billing::Customer>(&payments, &df!["id" => [1i64]].unwrap());
+    assert!(matches!(&report.errors()[0], ValidationError::OrphanedKeys { referenced, orphan_count: 1, .. } if referenced == "billing::Customer.id"));
+}
@@ -0,0 +1,130 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[polars(primary_key(order_id, line_no))]
+#[allow(dead_code)]
+struct OrderLine {
+    order_id: i64,
+    line_no: i32,
+    #[polars(unique, rename = "sku_code")]
+    sku: String,
+    quantity: i32,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Note {
+    text: String,
+}
+
+#[test]
+fn test_key_declarations() {
+    assert_eq!(OrderLine::primary_key(), Some(vec!["order_id", "line_no"]));
+    assert_eq!(Note::primary_key(), None);
+
+    let unique: Vec<_> = OrderLine::fields().iter().filter(|f| f.unique).map(|f| f.name).collect();
+    assert_eq!(unique, vec!["sku_code"]);
+}
+
+#[test]
+fn test_unique_keys_pass() {
+    let df = df![
+        "order_id" => [1i64, 1, 2],
+        "line_no" => [1, 2, 1],
+        "sku_code" => ["a", "b", "c"],
+        "quantity" => [1, 1, 1],
+    ].unwrap();
+
+    let report = OrderLine::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_duplicate_keys_reported() {
+    let df = df![
+        "order_id" => [1i64, 1, 2, 1, 2, 3],
+        "line_no" => [1, 2, 1, 1, 1, 1],
+        "sku_code" => ["a", "b", "a", "c", "d", "d"],
+        "quantity" => [1, 1, 1, 1, 1, 1],
+    ].unwrap();
+
+    let report = OrderLine::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::DuplicateKeys {
+                columns: vec!["sku_code".to_string()],
+                duplicate_count: 2,
+                sample: vec!["a".to_string(), "d".to_string()],
+            },
+            ValidationError::DuplicateKeys {
+                columns: vec!["order_id".to_string(), "line_no".to_string()],
+                duplicate_count: 2,
+                sample: vec!["(1, 1)".to_string(), "(2, 1)".to_string()],
+            },
+        ]
+    );
+    assert_eq!(
+        report.errors()[1].to_string(),
+        "Key (order_id, line_no) has 2 duplicate value(s) [(1, 1), (2, 1)]"
+    );
+}
+
+#[test]
+fn test_missing_key_column_not_checked_for_duplicates() {
+    let df = df![
+        "order_id" => [1i64, 1],
+        "sku_code" => ["a", "b"],
+        "quantity" => [1, 1],
+    ].unwrap();
+
+    let report = OrderLine::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::MissingColumn { column_name: "line_no".to_string() }]
+    );
+}
+
+#[test]
+fn test_null_keys_not_duplicates() {
+    #[derive(Debug, PolarsSchema)]
+    #[polars(primary_key(region, code))]
+    #[allow(dead_code)]
+    struct Store {
+        region: Option<String>,
+        code: i32,
+        #[polars(unique)]
+        email: Option<String>,
+    }
+
+    let df = df![
+        "region" => [Some("eu"), None, None, Some("eu")],
+        "code" => [1, 1, 1, 2],
+        "email" => [None, Some("a@example.com"), None, None],
+    ].unwrap();
+    let report = Store::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+
+    let df = df![
+        "region" => [Some("eu"), None, Some("eu")],
+        "code" => [1, 1, 1],
+        "email" => [Some("a@example.com"), None, Some("a@example.com")],
+    ].unwrap();
+    let report = Store::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::DuplicateKeys {
+                columns: vec!["email".to_string()],
+                duplicate_count: 1,
+                sample: vec!["a@example.com".to_string()],
+            },
+            ValidationError::DuplicateKeys {
+                columns: vec!["region".to_string(), "code".to_string()],
+                duplicate_count: 1,
+                sample: vec!["(eu, 1)".to_string()],
+            },
+        ]
+    );
+}
@@ -0,0 +1,68 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Summary {
+    customer: String,
+    total: f64,
+    orders: u32,
+}
+
+fn orders() -> LazyFrame {
+    df![
+        "customer" => ["Alice", "Bob", "Alice"],
+        "amount" => [10.0, 20.0, 5.0],
+    ].unwrap()
+    .lazy()
+}
+
+#[test]
+fn test_validate_lazy_valid_plan() {
+    let mut lf = orders()
+        .group_by([col("customer")])
+        .agg([col("amount").sum().alias("total"), len().alias("orders")]);
+
+    assert!(Summary::validate_lazy(&mut lf).is_ok());
+    assert!(Summary::validate_lazy_strict(&mut lf).is_ok());
+
+    // The plan is still usable after validation
+    assert_eq!(lf.collect().unwrap().height(), 2);
+}
+
+#[test]
+fn test_validate_lazy_wrong_plan() {
+    let mut lf = orders()
+        .group_by([col("customer")])
+        .agg([col("amount").sum().alias("total")]);
+
+    let error = Summary::validate_lazy(&mut lf).unwrap_err();
+    assert!(matches!(error, ValidationError::MissingColumn { ref column_name } if column_name == "orders"));
+
+    let mut lf = orders().with_column(col("amount").cast(DataType::Int64).alias("total"));
+    let error = Summary::validate_lazy(&mut lf).unwrap_err();
+    assert!(matches!(error, ValidationError::TypeMismatch { ref column_name, .. } if column_name == "total"));
+}
+
+#[test]
+fn test_validate_lazy_strict_extra_columns() {
+    let mut lf = orders()
+        .group_by([col("customer")])
+        .agg([
+            col("amount").sum().alias("total"),
+            len().alias("orders"),
+            col("amount").mean().alias("average"),
+        ]);
+
+    assert!(Summary::validate_lazy(&mut lf).is_ok());
+    let error = Summary::validate_lazy_strict(&mut lf).unwrap_err();
+    assert!(matches!(error, ValidationError::ColumnCountMismatch { expected_count: 3, actual_count: 4 }));
+}
+
+#[test]
+fn test_validate_lazy_unresolvable_plan() {
+    let mut lf = orders().select([col("does_not_exist")]);
+
+    let error = Summary::validate_lazy(&mut lf).unwrap_err();
+    assert!(matches!(error, ValidationError::Polars { .. }));
+}
@@ -0,0 +1,182 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsEnum, PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Item {
+    sku: String,
+    price: f64,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Basket {
+    id: i32,
+    scores: Vec<f64>,
+    tags: Option<Vec<Option<String>>>,
+    items: Vec<Item>,
+    position: [f32; 3],
+}
+
+#[test]
+fn test_list_and_array_dtypes() {
+    let schema = Basket::schema();
+    assert_eq!(schema[1].1, DataType::List(Box::new(DataType::Float64)));
+    assert_eq!(schema[2].1, DataType::List(Box::new(DataType::String)));
+    assert_eq!(
+        schema[3].1,
+        DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("sku".into(), DataType::String),
+            Field::new("price".into(), DataType::Float64),
+        ])))
+    );
+    assert_eq!(schema[4].1, DataType::Array(Box::new(DataType::Float32), 3));
+
+    let nullable: Vec<_> = Basket::fields().iter().map(|f| f.nullable).collect();
+    assert_eq!(nullable, vec![false, false, true, false, false]);
+}
+
+#[test]
+fn test_list_inner_type_mismatch_path() {
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    struct Scores {
+        scores: Vec<f64>,
+    }
+
+    let scores = Series::new("scores".into(), [1i64, 2, 3])
+        .implode()
+        .unwrap()
+        .into_series();
+    let df = DataFrame::new(vec![scores.into()]).unwrap();
+
+    let error = Scores::validate(&df).unwrap_err();
+    match error {
+        ValidationError::TypeMismatch { column_name, expected_type, actual_type } => {
+            assert_eq!(column_name, "scores[]");
+            assert_eq!(expected_type, "Float64");
+            assert_eq!(actual_type, "Int64");
+        }
+        _ => panic!("Expected TypeMismatch error, got: {:?}", error),
+    }
+
+    let scores = Series::new("scores".into(), [1.0f64, 2.0, 3.0])
+        .implode()
+        .unwrap()
+        .into_series();
+    let df = DataFrame::new(vec![scores.into()]).unwrap();
+    assert!(Scores::validate(&df).is_ok());
+}
+
+#[test]
+fn test_array_width_mismatch() {
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    struct Point {
+        position: [f32; 3],
+    }
+
+    let position = Series::new(
+        "position".into(),
+        [
+            Series::new("".into(), [1.0f32, 2.0]),
+            Series::new("".into(), [3.0f32, 4.0]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float32), 2))
+    .unwrap();
+    let df = DataFrame::new(vec![position.into()]).unwrap();
+
+    let error = Point::validate(&df).unwrap_err();
+    assert!(matches!(error, ValidationError::TypeMismatch { ref column_name, .. } if column_name == "position"));
+}
+
+#[derive(Debug, PolarsEnum)]
+#[allow(dead_code)]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Palette {
+    scores: Vec<f64>,
+    tags: Vec<Option<String>>,
+    names: Vec<String>,
+    #[polars(dtype = "List(String)")]
+    colors: Vec<Color>,
+    items: Vec<Item>,
+}
+
+#[test]
+fn test_list_item_checks() {
+    let item = |sku: Option<&str>, price: f64| {
+        df!["sku" => [sku], "price" => [price]]
+            .unwrap()
+            .lazy()
+            .select([as_struct(vec![col("sku"), col("price")]).alias("items")])
+            .collect()
+            .unwrap()
+            .column("items")
+            .unwrap()
+            .as_materialized_series()
+            .clone()
+    };
+    let df = df![
+        "scores" => [Series::new("".into(), [1.0, 2.0]), Series::new("".into(), [f64::NAN, 3.0])],
+        "tags" => [Series::new("".into(), [Some("a"), None]), Series::new("".into(), [Some("b")])],
+        "names" => [Series::new("".into(), [Some("a")]), Series::new("".into(), [Some("b"), None])],
+        "colors" => [Series::new("".into(), ["Red"]), Series::new("".into(), ["Green", "Blue"])],
+        "items" => [item(Some("x"), 1.0), item(None, f64::INFINITY)],
+    ]
+    .unwrap();
+
+    let report = Palette::validate_all(&df);
+    let failing: Vec<_> = report
+        .iter()
+        .map(|e| match e {
+            ValidationError::InvalidRows { column_name, check, sample, .. } => {
+                (column_name.as_str(), check.as_str(), sample.iter().map(|(row, _)| *row).collect::<Vec<_>>())
+            }
+            ValidationError::NullValues { column_name, rows, .. } => (column_name.as_str(), "not_null", rows.clone()),
+            ValidationError::InvalidCategory { column_name, rows, .. } => (column_name.as_str(), "category", rows.clone()),
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect();
+    assert_eq!(
+        failing,
+        vec![
+            ("scores[]", "not_nan", vec![1]),
+            ("names[]", "not_null", vec![1]),
+            ("colors[]", "category", vec![1]),
+            ("items[].sku", "not_null", vec![1]),
+            ("items[].price", "finite", vec![1]),
+        ]
+    );
+    assert!(matches!(&report.errors()[2], ValidationError::InvalidCategory { values, .. } if values == &["Blue"]));
+    assert!(matches!(&report.errors()[0], ValidationError::InvalidRows { sample, .. } if sample == &[(1, "[NaN]".to_string())]));
+}
+
+#[test]
+fn test_array_item_checks() {
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code)]
+    struct Point {
+        position: [f32; 2],
+    }
+
+    let position = Series::new(
+        "position".into(),
+        [
+            Series::new("".into(), [1.0f32, 2.0]),
+            Series::new("".into(), [3.0f32, f32::NAN]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float32), 2))
+    .unwrap();
+    let df = DataFrame::new(vec![position.into()]).unwrap();
+
+    let error = Point::validate(&df).unwrap_err();
+    assert!(matches!(error, ValidationError::InvalidRows { ref column_name, ref check, failing_count: 1, .. } if column_name == "position[]" && check == "not_nan"));
+}
@@ -0,0 +1,174 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+    zip_code: i32,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Customer {
+    id: i64,
+    address: Address,
+    billing: Option<Address>,
+}
+
+/// Builds a customer frame with `address` and `billing` struct columns from flat columns.
+fn customer_frame(flat: DataFrame) -> DataFrame {
+    flat.lazy()
+        .select([
+            col("id"),
+            as_struct(vec![col("street"), col("zip_code")]).alias("address"),
+            as_struct(vec![col("street"), col("zip_code")]).alias("billing"),
+        ])
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_nested_struct_dtype() {
+    let schema = Customer::schema();
+    let expected = DataType::Struct(vec![
+        Field::new("street".into(), DataType::String),
+        Field::new("zip_code".into(), DataType::Int32),
+    ]);
+    assert_eq!(schema[1], ("address", expected.clone()));
+    assert_eq!(schema[2], ("billing", expected));
+
+    let fields = Customer::fields();
+    assert_eq!(fields[1].fields, Address::fields());
+    assert!(fields[2].nullable);
+}
+
+#[test]
+fn test_nested_struct_valid() {
+    let df = customer_frame(df![
+        "id" => [1i64, 2i64],
+        "street" => ["Main St", "High St"],
+        "zip_code" => [12345, 54321],
+    ].unwrap());
+
+    assert!(Customer::validate(&df).is_ok());
+}
+
+#[test]
+fn test_nested_struct_errors_use_dotted_paths() {
+    let df = customer_frame(df![
+        "id" => [1i64, 2i64],
+        "street" => ["Main St", "High St"],
+        "zip_code" => ["12345", "54321"],
+    ].unwrap());
+
+    let report = Customer::validate_all(&df);
+    assert_eq!(report.len(), 2);
+    assert!(matches!(&report.errors()[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "address.zip_code"));
+    assert!(matches!(&report.errors()[1], ValidationError::TypeMismatch { column_name, .. } if column_name == "billing.zip_code"));
+}
+
+#[test]
+fn test_nested_struct_missing_and_unexpected_fields() {
+    let df = df![
+        "id" => [1i64],
+        "street" => ["Main St"],
+        "city" => ["Springfield"],
+    ].unwrap()
+    .lazy()
+    .select([
+        col("id"),
+        as_struct(vec![col("street"), col("city")]).alias("address"),
+        as_struct(vec![col("street"), col("city")]).alias("billing"),
+    ])
+    .collect()
+    .unwrap();
+
+    let report = Customer::validate_all(&df);
+    assert!(report.iter().any(|e| matches!(e, ValidationError::MissingColumn { column_name } if column_name == "address.zip_code")));
+    assert!(!report.iter().any(|e| matches!(e, ValidationError::UnexpectedColumn { .. })));
+
+    let report = Customer::validate_strict_all(&df);
+    assert!(report.iter().any(|e| matches!(e, ValidationError::UnexpectedColumn { column_name } if column_name == "address.city")));
+}
+
+#[test]
+fn test_nested_struct_null_fields() {
+    let df = customer_frame(df![
+        "id" => [1i64, 2i64, 3i64],
+        "street" => [Some("Main St"), None, Some("High St")],
+        "zip_code" => [12345, 54321, 11111],
+    ].unwrap());
+
+    let report = Customer::validate_all(&df);
+    let failing: Vec<_> = report
+        .iter()
+        .map(|e| match e {
+            ValidationError::NullValues { column_name, null_count, rows } => {
+                (column_name.as_str(), *null_count, rows.clone())
+            }
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect();
+    assert_eq!(failing, vec![("address.street", 1, vec![1]), ("billing.street", 1, vec![1])]);
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Reading {
+    #[polars(min = 0.0)]
+    value: f64,
+    #[polars(regex = "^[a-z]+$")]
+    unit: String,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Sensor {
+    id: i64,
+    reading: Option<Reading>,
+}
+
+#[test]
+fn test_nested_struct_field_checks() {
+    let df = df![
+        "id" => [1i64, 2i64, 3i64, 4i64],
+        "value" => [1.5, -1.0, f64::NAN, -2.0],
+        "unit" => ["kg", "kg", "KG", "kg"],
+    ].unwrap()
+    .lazy()
+    .select([
+        col("id"),
+        when(col("id").eq(lit(4i64)))
+            .then(lit(NULL).cast(DataType::Struct(vec![
+                Field::new("value".into(), DataType::Float64),
+                Field::new("unit".into(), DataType::String),
+            ])))
+            .otherwise(as_struct(vec![col("value"), col("unit")]))
+            .alias("reading"),
+    ])
+    .collect()
+    .unwrap();
+
+    let report = Sensor::validate_all(&df);
+    let failing: Vec<_> = report
+        .iter()
+        .map(|e| match e {
+            ValidationError::InvalidRows { column_name, check, sample, .. } => {
+                (column_name.as_str(), check.as_str(), sample.iter().map(|(row, _)| *row).collect::<Vec<_>>())
+            }
+            ValidationError::ConstraintViolation { column_name, constraint, sample, .. } => {
+                (column_name.as_str(), constraint.as_str(), sample.iter().map(|(row, _)| *row).collect())
+            }
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect();
+    assert_eq!(
+        failing,
+        vec![
+            ("reading.value", "not_nan", vec![2]),
+            ("reading.value", "min = 0.0", vec![1]),
+            ("reading.unit", "regex = \"^[a-z]+$\"", vec![2]),
+        ]
+    );
+}
@@ -0,0 +1,81 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Customer {
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    score: Option<f64>,
+}
+
+#[test]
+fn test_fields_carry_nullability() {
+    let fields = Customer::fields();
+    let nullable: Vec<_> = fields.iter().map(|f| (f.name, f.nullable)).collect();
+    assert_eq!(
+        nullable,
+        vec![("id", false), ("name", false), ("nickname", true), ("score", true)]
+    );
+
+    // Option<T> maps to the same dtype as T
+    assert_eq!(fields[1].dtype, fields[2].dtype);
+}
+
+#[test]
+fn test_nulls_allowed_in_option_fields() {
+    let df = df![
+        "id" => [1, 2, 3],
+        "name" => ["Alice", "Bob", "Charlie"],
+        "nickname" => [Some("Al"), None, None],
+        "score" => [None, Some(1.5), None],
+    ].unwrap();
+
+    assert!(Customer::validate(&df).is_ok());
+}
+
+#[test]
+fn test_nulls_rejected_in_required_fields() {
+    let df = df![
+        "id" => [Some(1), None, None],
+        "name" => [Some("Alice"), Some("Bob"), None],
+        "nickname" => [Some("Al"), None, None],
+        "score" => [None, Some(1.5), None],
+    ].unwrap();
+
+    let report = Customer::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::NullValues {
+                column_name: "id".to_string(),
+                null_count: 2,
+                rows: vec![1, 2],
+            },
+            ValidationError::NullValues {
+                column_name: "name".to_string(),
+                null_count: 1,
+                rows: vec![2],
+            },
+        ]
+    );
+
+    let error = Customer::validate(&df).unwrap_err();
+    assert_eq!(error.to_string(), "Column 'id' contains 2 null value(s) but is not nullable [rows 1, 2]");
+}
+
+#[test]
+fn test_manual_schema_defaults_to_nullable() {
+    struct Manual;
+
+    impl PolarsSchema for Manual {
+        fn schema() -> Vec<(&'static str, DataType)> {
+            vec![("value", DataType::Int32)]
+        }
+    }
+
+    let df = df!["value" => [Some(1), None]].unwrap();
+    assert!(Manual::fields()[0].nullable);
+    assert!(Manual::validate(&df).is_ok());
+}
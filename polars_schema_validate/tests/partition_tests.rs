@@ -0,0 +1,98 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError, VIOLATIONS_COLUMN};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Reading {
+    sensor: String,
+    #[polars(min = 0, max = 100)]
+    value: i64,
+    note: Option<String>,
+}
+
+fn violations(quarantine: &DataFrame) -> Vec<Vec<String>> {
+    let column = quarantine.column(VIOLATIONS_COLUMN).unwrap();
+    column
+        .list()
+        .unwrap()
+        .into_iter()
+        .map(|row| {
+            let row = row.unwrap();
+            row.str().unwrap().into_iter().map(|v| v.unwrap().to_string()).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_partition_splits_failing_rows() {
+    let df = df![
+        "sensor" => [Some("a"), None, Some("c"), None, Some("e")],
+        "value" => [10i64, 20, -5, 500, 50],
+        "note" => [None::<&str>, None, Some("x"), None, None],
+    ].unwrap();
+
+    let (valid, quarantine) = Reading::partition(&df).unwrap();
+
+    assert_eq!(valid.height(), 2);
+    assert_eq!(valid.get_column_names(), df.get_column_names());
+    assert_eq!(
+        valid.column("sensor").unwrap().str().unwrap().into_no_null_iter().collect::<Vec<_>>(),
+        vec!["a", "e"]
+    );
+    assert!(Reading::validate(&valid).is_ok());
+
+    assert_eq!(quarantine.height(), 3);
+    assert_eq!(
+        quarantine.column("value").unwrap().i64().unwrap().into_no_null_iter().collect::<Vec<_>>(),
+        vec![20, -5, 500]
+    );
+    assert_eq!(
+        violations(&quarantine),
+        vec![
+            vec!["sensor: not_null".to_string()],
+            vec!["value: min = 0".to_string()],
+            vec!["sensor: not_null".to_string(), "value: max = 100".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn test_partition_all_valid() {
+    let df = df![
+        "sensor" => ["a", "b"],
+        "value" => [1i64, 2],
+        "note" => [None::<&str>, None],
+    ].unwrap();
+
+    let (valid, quarantine) = Reading::partition(&df).unwrap();
+    assert_eq!(valid.height(), 2);
+    assert_eq!(quarantine.height(), 0);
+    assert_eq!(
+        quarantine.column(VIOLATIONS_COLUMN).unwrap().dtype(),
+        &DataType::List(Box::new(DataType::String))
+    );
+}
+
+#[test]
+fn test_partition_rejects_schema_errors() {
+    let df = df![
+        "sensor" => ["a"],
+        "note" => [None::<&str>],
+    ].unwrap();
+
+    let error = Reading::partition(&df).unwrap_err();
+    assert_eq!(error, ValidationError::MissingColumn { column_name: "value".to_string() });
+}
+
+#[test]
+fn test_partition_rejects_existing_violations_column() {
+    let df = df![
+        "sensor" => ["a"],
+        "value" => [1i64],
+        "note" => [None::<&str>],
+        VIOLATIONS_COLUMN => ["kept"],
+    ].unwrap();
+
+    let error = Reading::partition(&df).unwrap_err();
+    assert_eq!(error, ValidationError::UnexpectedColumn { column_name: VIOLATIONS_COLUMN.to_string() });
+}
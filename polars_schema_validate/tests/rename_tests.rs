@@ -0,0 +1,84 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct RawOrder {
+    #[polars(rename = "order id")]
+    order_id: i64,
+    #[polars(rename = "Customer-Name")]
+    customer_name: String,
+    amount: f64,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct CamelOrder {
+    order_id: i64,
+    total_amount_usd: f64,
+    #[polars(rename = "STATUS")]
+    status: String,
+    r#type: String,
+}
+
+#[test]
+fn test_field_rename() {
+    let names: Vec<_> = RawOrder::schema().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["order id", "Customer-Name", "amount"]);
+
+    let df = df![
+        "order id" => [1i64, 2i64],
+        "Customer-Name" => ["Alice", "Bob"],
+        "amount" => [1.0, 2.0],
+    ].unwrap();
+    assert!(RawOrder::validate_strict(&df).is_ok());
+}
+
+#[test]
+fn test_rename_all_with_field_override() {
+    let names: Vec<_> = CamelOrder::schema().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["orderId", "totalAmountUsd", "STATUS", "type"]);
+}
+
+#[test]
+fn test_rename_all_rules() {
+    #[derive(PolarsSchema)]
+    #[polars(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[allow(dead_code)]
+    struct Screaming {
+        order_id: i64,
+    }
+
+    #[derive(PolarsSchema)]
+    #[polars(rename_all = "kebab-case")]
+    #[allow(dead_code)]
+    struct Kebab {
+        order_id: i64,
+    }
+
+    #[derive(PolarsSchema)]
+    #[polars(rename_all = "PascalCase")]
+    #[allow(dead_code)]
+    struct Pascal {
+        order_id: i64,
+    }
+
+    assert_eq!(Screaming::schema()[0].0, "ORDER_ID");
+    assert_eq!(Kebab::schema()[0].0, "order-id");
+    assert_eq!(Pascal::schema()[0].0, "OrderId");
+}
+
+#[test]
+fn test_errors_use_renamed_columns() {
+    let df = df![
+        "order_id" => [1i64, 2i64],
+        "Customer-Name" => ["Alice", "Bob"],
+        "amount" => [1, 2],
+    ].unwrap();
+
+    let report = RawOrder::validate_all(&df);
+    assert!(matches!(&report.errors()[0], ValidationError::MissingColumn { column_name } if column_name == "order id"));
+    assert!(matches!(&report.errors()[1], ValidationError::TypeMismatch { column_name, .. } if column_name == "amount"));
+    assert!(report.to_string().contains("Column 'order id' not found in DataFrame"));
+}
@@ -0,0 +1,91 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Order {
+    order_id: i64,
+    customer: String,
+    amount: f64,
+    shipped: bool,
+}
+
+#[test]
+fn test_validate_all_valid_dataframe() {
+    let df = df![
+        "order_id" => [1i64, 2i64],
+        "customer" => ["Alice", "Bob"],
+        "amount" => [10.0, 20.0],
+        "shipped" => [true, false],
+    ].unwrap();
+
+    let report = Order::validate_all(&df);
+    assert!(report.is_valid());
+    assert_eq!(report.len(), 0);
+    assert!(report.into_result().is_ok());
+}
+
+#[test]
+fn test_validate_all_collects_every_error() {
+    let df = df![
+        "order_id" => ["1", "2"],          // Wrong type
+        "amount" => [10i32, 20i32],        // Wrong type
+        "shipped" => [true, false],
+        // Missing customer
+    ].unwrap();
+
+    let report = Order::validate_all(&df);
+    assert_eq!(report.len(), 3);
+
+    let errors: Vec<_> = report.iter().collect();
+    assert!(matches!(errors[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "order_id"));
+    assert!(matches!(errors[1], ValidationError::MissingColumn { column_name } if column_name == "customer"));
+    assert!(matches!(errors[2], ValidationError::TypeMismatch { column_name, .. } if column_name == "amount"));
+
+    // The single-error API still reports the first problem
+    let first = Order::validate(&df).unwrap_err();
+    assert_eq!(&first, errors[0]);
+}
+
+#[test]
+fn test_validate_strict_all_reports_unexpected_columns() {
+    let df = df![
+        "order_id" => [1i64, 2i64],
+        "amount" => [10.0, 20.0],
+        "shipped" => [true, false],
+        "extra_a" => [1, 2],
+        "extra_b" => ["x", "y"],
+    ].unwrap();
+
+    // Non-strict only sees the missing column
+    assert_eq!(Order::validate_all(&df).len(), 1);
+
+    let report = Order::validate_strict_all(&df);
+    let unexpected: Vec<_> = report
+        .iter()
+        .filter_map(|e| match e {
+            ValidationError::UnexpectedColumn { column_name } => Some(column_name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(report.len(), 3);
+    assert_eq!(unexpected, vec!["extra_a", "extra_b"]);
+}
+
+#[test]
+fn test_report_display_lists_all_errors() {
+    let df = df![
+        "order_id" => [1i64, 2i64],
+        "amount" => ["10", "20"],
+    ].unwrap();
+
+    let report = Order::validate_all(&df);
+    let message = report.to_string();
+    assert!(message.starts_with("DataFrame failed validation with 3 error(s):"));
+    assert!(message.contains("Column 'customer' not found in DataFrame"));
+    assert!(message.contains("Column 'amount' has type"));
+    assert!(message.contains("Column 'shipped' not found in DataFrame"));
+
+    let errors: Vec<ValidationError> = report.into_iter().collect();
+    assert_eq!(errors.len(), 3);
+}
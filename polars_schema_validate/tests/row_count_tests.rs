@@ -0,0 +1,77 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[polars(rows(min = 1, max = 3))]
+#[allow(dead_code)]
+struct Extract {
+    id: i64,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(rows(min = 10_000))]
+#[allow(dead_code)]
+struct LargeExtract {
+    id: i64,
+}
+
+#[test]
+fn test_row_bounds_declaration() {
+    assert_eq!(Extract::row_bounds(), (Some(1), Some(3)));
+    assert_eq!(LargeExtract::row_bounds(), (Some(10_000), None));
+}
+
+#[test]
+fn test_row_count_within_bounds() {
+    let df = df!["id" => [1i64, 2, 3]].unwrap();
+    assert!(Extract::validate(&df).is_ok());
+    assert!(Extract::validate_strict(&df).is_ok());
+}
+
+#[test]
+fn test_row_count_out_of_bounds() {
+    let empty = df!["id" => Vec::<i64>::new()].unwrap();
+    let error = Extract::validate(&empty).unwrap_err();
+    assert_eq!(error, ValidationError::RowCount { row_count: 0, min: Some(1), max: Some(3) });
+    assert_eq!(error.to_string(), "DataFrame has 0 row(s) but expected between 1 and 3");
+
+    let large = df!["id" => [1i64, 2, 3, 4]].unwrap();
+    let report = Extract::validate_strict_all(&large);
+    assert_eq!(report.errors(), &[ValidationError::RowCount { row_count: 4, min: Some(1), max: Some(3) }]);
+
+    let error = LargeExtract::validate(&large).unwrap_err();
+    assert_eq!(error.to_string(), "DataFrame has 4 row(s) but expected at least 10000");
+}
+
+#[test]
+fn test_lazy_row_count() {
+    let mut lf = df!["id" => [1i64, 2, 3, 4]].unwrap().lazy().filter(col("id").gt(lit(2)));
+    assert!(Extract::validate_lazy(&mut lf).is_ok());
+
+    let mut lf = df!["id" => [1i64, 2]].unwrap().lazy().filter(col("id").gt(lit(2)));
+    assert_eq!(
+        Extract::validate_lazy_strict(&mut lf).unwrap_err(),
+        ValidationError::RowCount { row_count: 0, min: Some(1), max: Some(3) }
+    );
+}
+
+#[test]
+fn test_row_count_reported_after_columns() {
+    let large = df!["id" => [Some(1i64), None, Some(3), Some(4)]].unwrap();
+    let report = Extract::validate_all(&large);
+    assert!(matches!(report.errors()[0], ValidationError::NullValues { .. }));
+    assert_eq!(report.errors()[1], ValidationError::RowCount { row_count: 4, min: Some(1), max: Some(3) });
+
+    let error = Extract::validate(&df!["id" => ["a"]].unwrap()).unwrap_err();
+    assert!(matches!(error, ValidationError::TypeMismatch { .. }));
+}
+
+#[test]
+fn test_lazy_row_count_skipped_on_schema_errors() {
+    // The plan fails if it runs, so only the schema can be checked
+    let mut lf = df!["id" => ["a"]].unwrap().lazy().with_column(
+        col("id").map(|_| polars_bail!(ComputeError: "plan was run"), GetOutput::same_type()),
+    );
+    assert!(matches!(Extract::validate_lazy(&mut lf).unwrap_err(), ValidationError::TypeMismatch { .. }));
+    assert!(matches!(Extract::validate_lazy_strict(&mut lf).unwrap_err(), ValidationError::TypeMismatch { .. }));
+}
@@ -0,0 +1,126 @@
+use polars::prelude::*;
+use polars::series::IsSorted;
+use polars_schema_validate::{PolarsSchema, SortOrder, Sortedness, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Quote {
+    ticker: String,
+    #[polars(strictly_increasing, sorted_within(group = "ticker"))]
+    ts: i64,
+    #[polars(sorted = "asc")]
+    seq: Option<u64>,
+    #[polars(sorted = "desc")]
+    remaining: i32,
+}
+
+fn failing(report: &polars_schema_validate::ValidationReport) -> Vec<(&str, &str, Vec<usize>)> {
+    report
+        .iter()
+        .map(|e| match e {
+            ValidationError::InvalidRows { column_name, check, sample, .. } => (
+                column_name.as_str(),
+                check.as_str(),
+                sample.iter().map(|(row, _)| *row).collect(),
+            ),
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect()
+}
+
+#[test]
+fn test_sortedness_declarations() {
+    let sorted: Vec<_> = Quote::fields().iter().map(|f| f.sorted).collect();
+    assert_eq!(
+        sorted,
+        vec![
+            None,
+            Some(Sortedness { order: SortOrder::StrictlyIncreasing, group: Some("ticker") }),
+            Some(Sortedness { order: SortOrder::Ascending, group: None }),
+            Some(Sortedness { order: SortOrder::Descending, group: None }),
+        ]
+    );
+}
+
+#[test]
+fn test_sorted_columns_pass() {
+    let df = df![
+        "ticker" => ["A", "B", "A", "B"],
+        "ts" => [1i64, 0, 2, 5],
+        "seq" => [Some(1u64), None, Some(1), Some(3)],
+        "remaining" => [3, 2, 2, 0],
+    ].unwrap();
+
+    let report = Quote::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_out_of_order_rows_reported() {
+    let df = df![
+        "ticker" => ["A", "B", "A", "A"],
+        "ts" => [1i64, 5, 10, 10],
+        "seq" => [Some(2u64), None, Some(1), Some(3)],
+        "remaining" => [3, 4, 2, 0],
+    ].unwrap();
+
+    let report = Quote::validate_all(&df);
+    // Row 2 of `seq` is compared with row 0, skipping the null
+    assert_eq!(
+        failing(&report),
+        vec![
+            ("ts", "strictly_increasing within ticker", vec![3]),
+            ("seq", "sorted = \"asc\"", vec![2]),
+            ("remaining", "sorted = \"desc\"", vec![1]),
+        ]
+    );
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Column 'ts' failed check strictly_increasing within ticker in 1 row(s) [row 3: 10]"
+    );
+}
+
+#[test]
+fn test_validate_and_mark_sorted() {
+    let mut df = df![
+        "ticker" => ["A", "A", "A"],
+        "ts" => [1i64, 2, 3],
+        "seq" => [Some(1u64), None, Some(3)],
+        "remaining" => [3, 2, 2],
+    ].unwrap();
+
+    Quote::validate_and_mark_sorted(&mut df).unwrap();
+    let flag = |name: &str| df.column(name).unwrap().as_materialized_series().is_sorted_flag();
+    assert_eq!(flag("remaining"), IsSorted::Descending);
+    // Grouped columns and columns with nulls are not flagged
+    assert_eq!(flag("ts"), IsSorted::Not);
+    assert_eq!(flag("seq"), IsSorted::Not);
+
+    let mut unsorted = df![
+        "ticker" => ["A", "A"],
+        "ts" => [1i64, 2],
+        "seq" => [Some(1u64), Some(2)],
+        "remaining" => [1, 2],
+    ].unwrap();
+    assert!(Quote::validate_and_mark_sorted(&mut unsorted).is_err());
+    assert_eq!(
+        unsorted.column("remaining").unwrap().as_materialized_series().is_sorted_flag(),
+        IsSorted::Not
+    );
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Tick {
+    #[polars(strictly_increasing, max_failures = 1)]
+    ts: i64,
+}
+
+#[test]
+fn test_tolerated_ordering_not_marked_sorted() {
+    let mut df = df!["ts" => [1i64, 3, 2]].unwrap();
+
+    // One row out of order is within the tolerance, but the column is not sorted
+    Tick::validate_and_mark_sorted(&mut df).unwrap();
+    assert_eq!(df.column("ts").unwrap().as_materialized_series().is_sorted_flag(), IsSorted::Not);
+}
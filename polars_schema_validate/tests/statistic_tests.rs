@@ -0,0 +1,196 @@
+use polars::prelude::*;
+use polars_schema_validate::{Expectation, PolarsDtype, PolarsSchema, Statistic, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Visit {
+    #[polars(distinct(min = 3, max = 300))]
+    country: String,
+    #[polars(max_null_fraction = 0.25)]
+    referrer: Option<String>,
+}
+
+#[test]
+fn test_expectation_declarations() {
+    let fields = Visit::fields();
+    assert_eq!(
+        fields[0].expectations,
+        vec![Expectation::new(Statistic::DistinctCount, Some(3.0), Some(300.0))]
+    );
+    assert_eq!(
+        fields[1].expectations,
+        vec![Expectation::new(Statistic::NullFraction, None, Some(0.25))]
+    );
+}
+
+#[test]
+fn test_statistics_within_bounds() {
+    let df = df![
+        "country" => ["DE", "FR", "US", "DE"],
+        "referrer" => [Some("a"), None, Some("b"), Some("c")],
+    ].unwrap();
+
+    let report = Visit::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_statistics_out_of_bounds() {
+    let df = df![
+        "country" => ["DE", "DE", "US", "DE"],
+        "referrer" => [Some("a"), None, None, Some("c")],
+    ].unwrap();
+
+    let report = Visit::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::StatisticOutOfRange {
+                column_name: "country".to_string(),
+                statistic: "distinct".to_string(),
+                observed: 2.0,
+                min: Some(3.0),
+                max: Some(300.0),
+            },
+            ValidationError::StatisticOutOfRange {
+                column_name: "referrer".to_string(),
+                statistic: "null_fraction".to_string(),
+                observed: 0.5,
+                min: None,
+                max: Some(0.25),
+            },
+        ]
+    );
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Column 'country' has distinct = 2 but expected between 3 and 300"
+    );
+    assert_eq!(
+        report.errors()[1].to_string(),
+        "Column 'referrer' has null_fraction = 0.5 but expected at most 0.25"
+    );
+}
+
+#[test]
+fn test_statistics_of_empty_frame_not_checked() {
+    let df = df![
+        "country" => Vec::<&str>::new(),
+        "referrer" => Vec::<Option<&str>>::new(),
+    ].unwrap();
+
+    // An empty frame has 0 distinct countries, but no null fraction
+    let report = Visit::validate_all(&df);
+    assert_eq!(report.len(), 1);
+    assert!(matches!(&report.errors()[0], ValidationError::StatisticOutOfRange { column_name, .. } if column_name == "country"));
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Payment {
+    #[polars(mean(min = 10.0, max = 500.0), sum(max = 10000), std(max = 200))]
+    #[polars(min_value(min = 0), max_value(max = 1000), quantile(0.9, max = 600))]
+    amount: f64,
+    currency: String,
+}
+
+#[test]
+fn test_aggregate_statistics_within_bounds() {
+    let df = df![
+        "amount" => [20.0, 40.0, 60.0, 80.0, 100.0],
+        "currency" => ["EUR", "EUR", "USD", "USD", "EUR"],
+    ].unwrap();
+
+    let report = Payment::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_aggregate_statistics_catch_unit_errors() {
+    // Amounts in cents instead of dollars
+    let df = df![
+        "amount" => [2000.0, 4000.0, 6000.0, 8000.0, 10000.0],
+        "currency" => ["EUR", "EUR", "USD", "USD", "EUR"],
+    ].unwrap();
+
+    let report = Payment::validate_all(&df);
+    let statistics: Vec<_> = report
+        .iter()
+        .map(|e| match e {
+            ValidationError::StatisticOutOfRange { statistic, observed, .. } => (statistic.as_str(), *observed),
+            _ => panic!("Unexpected error: {:?}", e),
+        })
+        .collect();
+
+    assert_eq!(statistics[0], ("mean", 6000.0));
+    assert_eq!(statistics[1], ("sum", 30000.0));
+    assert_eq!(statistics[2].0, "std");
+    assert!((statistics[2].1 - 3162.2776).abs() < 1e-3);
+    assert_eq!(statistics[3], ("max_value", 10000.0));
+    assert_eq!(statistics[4], ("quantile(0.9)", 9200.0));
+    assert_eq!(statistics.len(), 5);
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Column 'amount' has mean = 6000 but expected between 10 and 500"
+    );
+}
+
+#[test]
+fn test_nan_statistic_out_of_range() {
+    let df = df![
+        "amount" => [20.0, f64::NAN, 60.0],
+        "currency" => ["EUR", "EUR", "USD"],
+    ].unwrap();
+
+    // The NaN itself is reported by the `not_nan` check as well
+    let report = Payment::validate_all(&df);
+    let statistics: Vec<_> = report
+        .iter()
+        .filter_map(|e| match e {
+            ValidationError::StatisticOutOfRange { statistic, observed, .. } => Some((statistic.as_str(), *observed)),
+            _ => None,
+        })
+        .collect();
+    assert!(statistics.iter().any(|(statistic, observed)| *statistic == "mean" && observed.is_nan()), "{}", report);
+}
+
+#[test]
+fn test_numeric_statistics_of_null_columns_not_checked() {
+    let df = df![
+        "amount" => [None::<f64>, None],
+        "currency" => ["EUR", "USD"],
+    ].unwrap();
+
+    // Only the null values are reported, sum of the missing amounts is not checked
+    let report = Payment::validate_all(&df);
+    assert_eq!(report.len(), 1);
+    assert!(matches!(&report.errors()[0], ValidationError::NullValues { column_name, .. } if column_name == "amount"));
+}
+
+#[test]
+fn test_numeric_statistic_of_non_numeric_column_reported() {
+    // Checked against the dtype at runtime only, as the derive cannot see through it
+    struct Currency;
+
+    impl PolarsDtype for Currency {
+        fn dtype() -> DataType {
+            DataType::String
+        }
+    }
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code)]
+    struct Price {
+        #[polars(mean(min = 1))]
+        currency: Currency,
+    }
+
+    let report = Price::validate_all(&df!["currency" => ["EUR", "USD"]].unwrap());
+    assert_eq!(
+        report.errors(),
+        &[ValidationError::TypeMismatch {
+            column_name: "currency".to_string(),
+            expected_type: "numeric dtype for mean".to_string(),
+            actual_type: "String".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "chrono")]
+
+use std::time::Duration;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, TimeSeries, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[polars(timeseries(time = "ts", every = "1m", by = "sensor_id", max_gap = "5m"))]
+#[allow(dead_code)]
+struct Reading {
+    sensor_id: String,
+    ts: NaiveDateTime,
+    value: f64,
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(timeseries(time = "day", every = "1d"))]
+#[allow(dead_code)]
+struct DailyTotal {
+    day: NaiveDate,
+    total: i64,
+}
+
+fn readings(sensors: &[&str], seconds: &[i64]) -> DataFrame {
+    let micros: Vec<_> = seconds.iter().map(|s| s * 1_000_000).collect();
+    df![
+        "sensor_id" => sensors,
+        "ts" => micros,
+        "value" => vec![1.0; sensors.len()],
+    ]
+    .unwrap()
+    .lazy()
+    .with_column(col("ts").cast(DataType::Datetime(TimeUnit::Microseconds, None)))
+    .collect()
+    .unwrap()
+}
+
+#[test]
+fn test_timeseries_declaration() {
+    assert_eq!(
+        Reading::timeseries(),
+        Some(TimeSeries {
+            time: "ts",
+            by: Some("sensor_id"),
+            every: Some(Duration::from_secs(60)),
+            max_gap: Some(Duration::from_secs(300)),
+        })
+    );
+    assert_eq!(DailyTotal::timeseries().unwrap().max_gap, None);
+}
+
+#[test]
+fn test_regular_timeseries_passes() {
+    // Rows need not be sorted, and gaps up to max_gap are allowed
+    let df = readings(&["s1", "s2", "s1", "s1", "s2", "s1"], &[0, 660, 60, 120, 600, 300]);
+
+    let report = Reading::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_duplicates_off_grid_and_gaps_reported() {
+    let df = readings(&["s1", "s1", "s1", "s1", "s2", "s2"], &[0, 60, 60, 600, 0, 90]);
+
+    let report = Reading::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::DuplicateKeys {
+                columns: vec!["sensor_id".to_string(), "ts".to_string()],
+                duplicate_count: 1,
+                sample: vec!["(s1, 1970-01-01 00:01:00)".to_string()],
+            },
+            ValidationError::InvalidRows {
+                column_name: "ts".to_string(),
+                check: "every = \"1m\"".to_string(),
+                failing_count: 1,
+                sample: vec![(5, "1970-01-01 00:01:30".to_string())],
+            },
+            ValidationError::TimeGaps {
+                column_name: "ts".to_string(),
+                max_gap: "5m".to_string(),
+                gap_count: 1,
+                sample: vec!["s1: 1970-01-01 00:01:00 -> 1970-01-01 00:10:00".to_string()],
+            },
+        ]
+    );
+    assert_eq!(
+        report.errors()[2].to_string(),
+        "Column 'ts' has 1 gap(s) longer than 5m [s1: 1970-01-01 00:01:00 -> 1970-01-01 00:10:00]"
+    );
+}
+
+#[test]
+fn test_gaps_default_to_grid_interval() {
+    let df = df!["day" => [0i32, 1, 3, 4], "total" => [1i64, 2, 3, 4]]
+        .unwrap()
+        .lazy()
+        .with_column(col("day").cast(DataType::Date))
+        .collect()
+        .unwrap();
+
+    let error = DailyTotal::validate(&df).unwrap_err();
+    assert_eq!(error.to_string(), "Column 'day' has 1 gap(s) longer than 1d [1970-01-02 -> 1970-01-04]");
+}
@@ -0,0 +1,152 @@
+use polars::prelude::*;
+use polars_schema_validate::{Constraint, ConstraintValue, PolarsSchema, Tolerance, Tolerances, ValidationError};
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Contact {
+    #[polars(regex = "^[^@]+@[^@]+$", mostly = 0.99)]
+    email: String,
+    #[polars(min = 0, max_failures = 2)]
+    #[polars(max = 150)]
+    age: i32,
+}
+
+fn contact_frame(bad_emails: usize, negative_ages: usize) -> DataFrame {
+    let emails: Vec<_> = (0..200)
+        .map(|i| if i < bad_emails { format!("user{}", i) } else { format!("user{}@example.com", i) })
+        .collect();
+    let ages: Vec<_> = (0..200).map(|i| if i < negative_ages { -1 } else { 30 }).collect();
+    df!["email" => emails, "age" => ages].unwrap()
+}
+
+#[test]
+fn test_tolerance_declarations() {
+    let fields = Contact::fields();
+    assert_eq!(
+        fields[1].constraints,
+        vec![
+            Constraint::Tolerant(Box::new(Constraint::Min(ConstraintValue::Int(0))), Tolerance::MaxFailures(2)),
+            Constraint::Max(ConstraintValue::Int(150)),
+        ]
+    );
+    assert!(matches!(fields[0].constraints[0], Constraint::Tolerant(_, Tolerance::Mostly(fraction)) if fraction == 0.99));
+}
+
+#[test]
+fn test_failures_within_tolerance_pass() {
+    let df = contact_frame(1, 2);
+
+    let report = Contact::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+    assert_eq!(
+        report.tolerated(),
+        &[
+            ValidationError::FailureRate {
+                column_name: "email".to_string(),
+                check: "regex = \"^[^@]+@[^@]+$\"".to_string(),
+                failing_count: 1,
+                row_count: 200,
+                tolerance: "mostly = 0.99".to_string(),
+                sample: vec![(0, "user0".to_string())],
+            },
+            ValidationError::FailureRate {
+                column_name: "age".to_string(),
+                check: "min = 0".to_string(),
+                failing_count: 2,
+                row_count: 200,
+                tolerance: "max_failures = 2".to_string(),
+                sample: vec![(0, "-1".to_string()), (1, "-1".to_string())],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_observed_rate_reported_without_failures() {
+    let report = Contact::validate_all(&contact_frame(0, 0));
+    assert!(report.is_valid());
+    assert_eq!(report.tolerated().len(), 2);
+    assert!(matches!(&report.tolerated()[0], ValidationError::FailureRate { failing_count: 0, .. }));
+}
+
+#[test]
+fn test_failures_beyond_tolerance_reported() {
+    let df = contact_frame(3, 3);
+
+    let report = Contact::validate_all(&df);
+    assert_eq!(report.len(), 2);
+    assert!(report.tolerated().is_empty());
+    assert_eq!(
+        report.errors()[0].to_string(),
+        "Column 'email' failed check regex = \"^[^@]+@[^@]+$\" in 3 of 200 row(s) (1.50%, mostly = 0.99) \
+         [row 0: user0, row 1: user1, row 2: user2]"
+    );
+    assert!(matches!(&report.errors()[1], ValidationError::FailureRate { column_name, failing_count: 3, .. } if column_name == "age"));
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code)]
+struct Reading {
+    #[polars(not_null, mostly = 0.5)]
+    sensor: String,
+    #[polars(not_nan, max_failures = 1)]
+    value: f64,
+    #[polars(sorted = "asc", max_failures = 1)]
+    timestamp: i64,
+    #[polars(not_null, max_failures = 0)]
+    note: Option<String>,
+    #[polars(finite, max_failures = 1)]
+    history: Vec<f64>,
+}
+
+#[test]
+fn test_check_tolerance_declarations() {
+    let fields = Reading::fields();
+    assert_eq!(fields[0].tolerances, Tolerances { not_null: Some(Tolerance::Mostly(0.5)), ..Default::default() });
+    assert_eq!(fields[1].tolerances.not_nan, Some(Tolerance::MaxFailures(1)));
+    assert_eq!(fields[2].tolerances.sorted, Some(Tolerance::MaxFailures(1)));
+    assert_eq!(Contact::fields()[0].tolerances, Tolerances::default());
+}
+
+#[test]
+fn test_checks_with_tolerance() {
+    let df = df![
+        "sensor" => [Some("a"), None, Some("c")],
+        "value" => [1.0, f64::NAN, 3.0],
+        "timestamp" => [1i64, 3, 2],
+        "note" => [Some("ok"), None, Some("ok")],
+        "history" => [
+            Series::new("".into(), [1.0, f64::INFINITY]),
+            Series::new("".into(), [2.0]),
+            Series::new("".into(), [f64::INFINITY]),
+        ],
+    ].unwrap();
+
+    let report = Reading::validate_all(&df);
+    let failures = |errors: &[ValidationError]| {
+        errors
+            .iter()
+            .map(|error| match error {
+                ValidationError::FailureRate { column_name, check, failing_count, .. } => {
+                    (column_name.clone(), check.clone(), *failing_count)
+                }
+                error => panic!("unexpected error {}", error),
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        failures(report.tolerated()),
+        vec![
+            ("sensor".to_string(), "not_null".to_string(), 1),
+            ("value".to_string(), "not_nan".to_string(), 1),
+            ("timestamp".to_string(), "sorted = \"asc\"".to_string(), 1),
+        ]
+    );
+    assert_eq!(
+        failures(report.errors()),
+        vec![
+            ("note".to_string(), "not_null".to_string(), 1),
+            ("history[]".to_string(), "finite".to_string(), 2),
+        ]
+    );
+}
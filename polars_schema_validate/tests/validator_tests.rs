@@ -0,0 +1,124 @@
+use polars::prelude::*;
+use polars_schema_validate::{PolarsSchema, Result, ValidationError};
+
+mod checksum {
+    use polars::prelude::*;
+    use polars_schema_validate::{Result, ValidationError};
+
+    /// Account numbers end with the sum of their other digits modulo 10.
+    pub fn account_number(series: &Series) -> Result<()> {
+        let invalid: Vec<_> = series
+            .str()?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(row, value)| Some((row, value?)))
+            .filter(|(_, value)| !valid_checksum(value))
+            .map(|(row, value)| (row, value.to_string()))
+            .collect();
+
+        if invalid.is_empty() {
+            return Ok(());
+        }
+        Err(ValidationError::InvalidRows {
+            column_name: series.name().to_string(),
+            check: "checksum".to_string(),
+            failing_count: invalid.len(),
+            sample: invalid,
+        })
+    }
+
+    fn valid_checksum(value: &str) -> bool {
+        let digits: Option<Vec<u32>> = value.chars().map(|c| c.to_digit(10)).collect();
+        match digits.as_deref() {
+            Some([body @ .., check]) if !body.is_empty() => body.iter().sum::<u32>() % 10 == *check,
+            _ => false,
+        }
+    }
+}
+
+fn balanced(df: &DataFrame) -> Result<()> {
+    let total = df.column("debit")?.as_materialized_series().sum::<f64>()?
+        - df.column("credit")?.as_materialized_series().sum::<f64>()?;
+    if total.abs() < 1e-9 {
+        Ok(())
+    } else {
+        Err(ValidationError::FailedCheck {
+            check: "balanced".to_string(),
+            failing_count: df.height(),
+            rows: Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[polars(validate_with = "balanced")]
+#[allow(dead_code)]
+struct Booking {
+    #[polars(validate_with = "checksum::account_number")]
+    account: String,
+    debit: f64,
+    credit: f64,
+}
+
+#[test]
+fn test_validator_declarations() {
+    let fields = Booking::fields();
+    let names: Vec<_> = fields[0].validators.iter().map(|v| v.name).collect();
+    assert_eq!(names, vec!["checksum::account_number"]);
+    assert!(fields[1].validators.is_empty());
+
+    let names: Vec<_> = Booking::validators().iter().map(|v| v.name).collect();
+    assert_eq!(names, vec!["balanced"]);
+}
+
+#[test]
+fn test_validators_pass() {
+    let df = df![
+        "account" => ["1236", "5050"],
+        "debit" => [10.0, 0.0],
+        "credit" => [0.0, 10.0],
+    ].unwrap();
+
+    let report = Booking::validate_all(&df);
+    assert!(report.is_valid(), "{}", report);
+}
+
+#[test]
+fn test_validator_errors_merged_into_report() {
+    let df = df![
+        "account" => ["1236", "1234", "12a4"],
+        "debit" => [10.0, 0.0, 5.0],
+        "credit" => [0.0, 10.0, 0.0],
+    ].unwrap();
+
+    let report = Booking::validate_all(&df);
+    assert_eq!(
+        report.errors(),
+        &[
+            ValidationError::InvalidRows {
+                column_name: "account".to_string(),
+                check: "checksum".to_string(),
+                failing_count: 2,
+                sample: vec![(1, "1234".to_string()), (2, "12a4".to_string())],
+            },
+            ValidationError::FailedCheck {
+                check: "balanced".to_string(),
+                failing_count: 3,
+                rows: Vec::new(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_field_validator_skipped_for_wrong_dtype() {
+    let df = df![
+        "account" => [1236i64],
+        "debit" => [0.0],
+        "credit" => [0.0],
+    ].unwrap();
+
+    let report = Booking::validate_all(&df);
+    assert_eq!(report.len(), 1);
+    assert!(matches!(&report.errors()[0], ValidationError::TypeMismatch { column_name, .. } if column_name == "account"));
+}